@@ -0,0 +1,68 @@
+//local shortcuts
+
+//third-party shortcuts
+
+//standard shortcuts
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, DeriveInput, Path, Type};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) fn derive_callable_system_impl(input: TokenStream) -> TokenStream
+{
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    // parse the #[callable(...)] attribute
+    let mut arg_type: Type = parse_quote! { () };
+    let mut out_type: Type = parse_quote! { () };
+    let mut run_fn: Option<Path> = None;
+
+    for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident("callable"))
+    {
+        let result = attr.parse_nested_meta(
+                |meta|
+                {
+                    if meta.path.is_ident("arg")      { arg_type = meta.value()?.parse()?; }
+                    else if meta.path.is_ident("out") { out_type = meta.value()?.parse()?; }
+                    else if meta.path.is_ident("run") { run_fn = Some(meta.value()?.parse()?); }
+                    else { return Err(meta.error("expected `arg`, `out`, or `run`")); }
+                    Ok(())
+                }
+            );
+        if let Err(err) = result { return err.into_compile_error().into(); }
+    }
+
+    let Some(run_fn) = run_fn
+    else
+    {
+        return syn::Error::new_spanned(&ast.ident, "missing #[callable(run = ...)] attribute")
+            .into_compile_error()
+            .into();
+    };
+
+    // unpack into trait implementation
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let struct_name = &ast.ident;
+
+    TokenStream::from(quote! {
+        impl #impl_generics ::bevy_kot_ecs::CallableSystem for #struct_name #ty_generics #where_clause
+        {
+            type ArgT = #arg_type;
+            type OutT = #out_type;
+
+            fn system(
+                world : &mut ::bevy::prelude::World,
+                state : &mut ::bevy::ecs::system::SystemState<Self>,
+                id    : ::bevy_kot_ecs::CallId,
+                arg   : Self::ArgT
+            ) -> Self::OutT
+            {
+                let mut context = state.get_mut(world);
+                #run_fn(&mut context, id, arg)
+            }
+        }
+    })
+}
+
+//-------------------------------------------------------------------------------------------------------------------
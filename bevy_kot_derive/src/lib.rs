@@ -1,4 +1,5 @@
 //module tree
+mod callable;
 mod react;
 mod style;
 
@@ -7,6 +8,35 @@ use proc_macro::TokenStream;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Derives `CallableSystem` for a `SystemParam` struct.
+///
+/// The `run` function receives the extracted system param, the call id, and the call argument:
+/// ```ignore
+/// #[derive(SystemParam, CallableSystem)]
+/// #[callable(arg = u32, out = u32, run = add_to_counter)]
+/// struct Counter<'w, 's>
+/// {
+///     counter: Local<'s, u32>,
+///     #[system_param(ignore)]
+///     _phantom: PhantomData<&'w ()>,
+/// }
+///
+/// fn add_to_counter(context: &mut Counter, _id: CallId, amount: u32) -> u32
+/// {
+///     *context.counter += amount;
+///     *context.counter
+/// }
+/// ```
+///
+/// `arg` and `out` default to `()`.
+#[proc_macro_derive(CallableSystem, attributes(callable))]
+pub fn derive_callable_system(input: TokenStream) -> TokenStream
+{
+    callable::derive_callable_system_impl(input)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 #[proc_macro_derive(ReactComponent)]
 pub fn derive_react_component(input: TokenStream) -> TokenStream
 {
@@ -1,39 +1,194 @@
 //local shortcuts
+use crate::*;
 use bevy_kot_utils::*;
 
 //third-party shortcuts
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::hierarchy::despawn_with_children_recursive;
 use bevy::prelude::*;
 
 //standard shortcuts
-use std::sync::Arc;
+use std::panic::Location;
+use std::sync::{Arc, Weak};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// How an entity is cleaned up when its [`AutoDespawnSignal`] is dropped.
+enum DespawnPolicy
+{
+    Despawn,
+    DespawnRecursive,
+    /// Despawn unless the entity has owners (see [`Owned`]).
+    DespawnUnowned,
+    Remove(fn(&mut World, Entity), &'static str),
+    Custom(CallbackSystem<Entity, ()>),
+}
+
+impl DespawnPolicy
+{
+    fn describe(&self) -> String
+    {
+        match self
+        {
+            DespawnPolicy::Despawn          => String::from("despawn"),
+            DespawnPolicy::DespawnRecursive => String::from("despawn recursive"),
+            DespawnPolicy::DespawnUnowned   => String::from("despawn unowned"),
+            DespawnPolicy::Remove(_, name)  => format!("remove {name}"),
+            DespawnPolicy::Custom(cleanup)  =>
+            {
+                match cleanup
+                {
+                    CallbackSystem::New(system) | CallbackSystem::Initialized(system) => format!("cleanup {}", system.name()),
+                    CallbackSystem::Empty => String::from("cleanup"),
+                }
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// State shared between an [`AutoDespawner`] and its signals.
+#[derive(Default)]
+struct AutoDespawnShared
+{
+    outstanding_signals : AtomicUsize,
+    log_drops           : AtomicBool,
+}
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
 struct AutoDespawnSignalInner
 {
-    entity: Entity,
-    sender: Sender<Entity>,
+    entity   : Entity,
+    policy   : Option<DespawnPolicy>,
+    location : &'static Location<'static>,
+    sender   : Sender<(Entity, DespawnPolicy)>,
+    shared   : Arc<AutoDespawnShared>,
 }
 
 impl Drop for AutoDespawnSignalInner
 {
     fn drop(&mut self)
     {
-        let _ = self.sender.send(self.entity);
+        self.shared.outstanding_signals.fetch_sub(1, Ordering::Relaxed);
+        let Some(policy) = self.policy.take() else { return; };
+
+        if self.shared.log_drops.load(Ordering::Relaxed)
+        {
+            tracing::info!(entity = ?self.entity, policy = policy.describe(), prepared_at = %self.location,
+                "auto despawn signal dropped");
+        }
+
+        let _ = self.sender.send((self.entity, policy));
     }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn auto_despawn(mut commands: Commands, despawner: Res<AutoDespawner>)
+fn remove_bundle<B: Bundle>(world: &mut World, entity: Entity)
+{
+    let Some(mut entity_mut) = world.get_entity_mut(entity) else { return; };
+    entity_mut.remove::<B>();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the schedules that auto despawning runs in.
+#[derive(Resource, Default)]
+struct AutoDespawnSchedules
+{
+    /// Schedules where dropped signals are currently handled.
+    active: Vec<InternedScheduleLabel>,
+    /// Schedules that have a flush system (systems can't be removed, so inactive schedules skip the flush).
+    registered: Vec<InternedScheduleLabel>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Run condition that checks if auto despawning is active in `label`.
+fn auto_despawn_schedule_is_active(
+    label: InternedScheduleLabel
+) -> impl Fn(Res<AutoDespawnSchedules>) -> bool + Send + Sync + 'static
 {
-    while let Some(entity) = despawner.try_recv()
+    move |schedules: Res<AutoDespawnSchedules>| schedules.active.contains(&label)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set where entities with dropped [`AutoDespawnSignal`]s are despawned.
+///
+/// The set runs in `Last` by default. Use [`AutoDespawnAppExt::add_auto_despawn_schedule()`] to despawn entities in
+/// other schedules as well, [`AutoDespawnAppExt::set_auto_despawn_schedule()`] to replace the default schedule, and
+/// `configure_sets()` to order the set relative to other systems.
+#[derive(SystemSet, Debug, Default, Hash, Eq, PartialEq, Clone)]
+pub struct AutoDespawnSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Immediately despawn (or clean up) all entities whose [`AutoDespawnSignal`]s have been dropped.
+///
+/// This is the system that runs in [`AutoDespawnSet`]. It can be called from exclusive systems and tests to clean up
+/// dropped reactors and spawned systems before later logic in the same frame observes them. Cleanup that drops other
+/// signals will be handled in the same flush.
+///
+/// Entities whose ownership signals were dropped are not despawned if they currently have owners (see [`Owned`]).
+///
+/// Does nothing if auto despawning was not set up.
+pub fn flush_auto_despawns(world: &mut World)
+{
+    let Some(despawner) = world.get_resource::<AutoDespawner>().cloned() else { return; };
+    let mut count = 0;
+
+    while let Some((entity, policy)) = despawner.try_recv()
     {
-        let Some(mut entity_commands) = commands.get_entity(entity) else { continue; };
-        entity_commands.despawn();
+        count += 1;
+        if world.get_entity(entity).is_none() { continue; }
+
+        match policy
+        {
+            DespawnPolicy::Despawn          => { world.despawn(entity); }
+            DespawnPolicy::DespawnRecursive => despawn_with_children_recursive(world, entity),
+            DespawnPolicy::DespawnUnowned   =>
+            {
+                // an owned entity may be released then re-owned before the flush, which leaves a stale despawn message
+                if entity_is_owned(world, entity) { continue; }
+                world.despawn(entity);
+            }
+            DespawnPolicy::Remove(remove, _) => remove(world, entity),
+            DespawnPolicy::Custom(mut cleanup) => { let _ = cleanup.run(world, entity); }
+        }
     }
+
+    // update diagnostics
+    let outstanding_signals = despawner.outstanding_signals();
+    let mut diagnostics = world.get_resource_or_insert_with(AutoDespawnDiagnostics::default);
+    diagnostics.outstanding_signals = outstanding_signals;
+    diagnostics.last_flush_despawns = count;
+    diagnostics.total_despawns += count;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Auto despawn statistics, updated every time [`flush_auto_despawns()`] runs.
+///
+/// Use [`AutoDespawner::outstanding_signals()`] and [`AutoDespawner::pending_despawns()`] for up-to-date counts.
+#[derive(Resource, Default, Debug, Copy, Clone)]
+pub struct AutoDespawnDiagnostics
+{
+    /// Number of live signals (each clone set counts once) at the end of the last flush.
+    pub outstanding_signals: usize,
+    /// Number of dropped signals handled in the last flush.
+    pub last_flush_despawns: usize,
+    /// Total number of dropped signals handled.
+    pub total_despawns: usize,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -43,8 +198,9 @@ fn auto_despawn(mut commands: Commands, despawner: Res<AutoDespawner>)
 #[derive(Resource, Clone)]
 pub struct AutoDespawner
 {
-    sender: Sender<Entity>,
-    receiver: Receiver<Entity>,
+    sender: Sender<(Entity, DespawnPolicy)>,
+    receiver: Receiver<(Entity, DespawnPolicy)>,
+    shared: Arc<AutoDespawnShared>,
 }
 
 impl AutoDespawner
@@ -52,18 +208,96 @@ impl AutoDespawner
     fn new() -> Self
     {
         let (sender, receiver) = new_channel();
-        Self{ sender, receiver }
+        Self{ sender, receiver, shared: Arc::new(AutoDespawnShared::default()) }
+    }
+
+    /// Get the number of live signals. Clones of a signal are counted once.
+    pub fn outstanding_signals(&self) -> usize
+    {
+        self.shared.outstanding_signals.load(Ordering::Relaxed)
+    }
+
+    /// Get the number of dropped signals waiting to be handled.
+    pub fn pending_despawns(&self) -> usize
+    {
+        self.receiver.len()
+    }
+
+    /// Log entity and policy info (and where the signal was prepared) when signals are dropped.
+    ///
+    /// Useful for finding out what is keeping entities alive.
+    pub fn set_drop_logging(&self, enabled: bool)
+    {
+        self.shared.log_drops.store(enabled, Ordering::Relaxed);
+    }
+
+    #[track_caller]
+    fn signal(&self, entity: Entity, policy: DespawnPolicy) -> AutoDespawnSignal
+    {
+        self.shared.outstanding_signals.fetch_add(1, Ordering::Relaxed);
+        AutoDespawnSignal(Arc::new(
+                AutoDespawnSignalInner{
+                    entity,
+                    policy   : Some(policy),
+                    location : Location::caller(),
+                    sender   : self.sender.clone(),
+                    shared   : self.shared.clone(),
+                }
+            ))
     }
 
     /// Prepare an entity to be automatically despawned.
     ///
-    /// When the last copy of the returned signal is dropped, the entity will be despawned in the `Last` schedule.
+    /// When the last copy of the returned signal is dropped, the entity will be despawned in [`AutoDespawnSet`].
+    #[track_caller]
     pub fn prepare(&self, entity: Entity) -> AutoDespawnSignal
     {
-        AutoDespawnSignal::new(entity, self.sender.clone())
+        self.signal(entity, DespawnPolicy::Despawn)
+    }
+
+    /// Prepare an entity to be automatically despawned along with its children.
+    ///
+    /// When the last copy of the returned signal is dropped, the entity and its descendants will be despawned in
+    /// [`AutoDespawnSet`].
+    #[track_caller]
+    pub fn prepare_recursive(&self, entity: Entity) -> AutoDespawnSignal
+    {
+        self.signal(entity, DespawnPolicy::DespawnRecursive)
+    }
+
+    /// Prepare an owned entity to be automatically despawned if it has no owners when the signal is handled.
+    #[track_caller]
+    pub(crate) fn prepare_owned(&self, entity: Entity) -> AutoDespawnSignal
+    {
+        self.signal(entity, DespawnPolicy::DespawnUnowned)
+    }
+
+    /// Prepare bundle `B` to be automatically removed from an entity.
+    ///
+    /// When the last copy of the returned signal is dropped, the bundle's components will be removed in
+    /// [`AutoDespawnSet`]. The entity itself is not despawned.
+    #[track_caller]
+    pub fn prepare_remove<B: Bundle>(&self, entity: Entity) -> AutoDespawnSignal
+    {
+        self.signal(entity, DespawnPolicy::Remove(remove_bundle::<B>, std::any::type_name::<B>()))
     }
 
-    fn try_recv(&self) -> Option<Entity>
+    /// Prepare an entity to be automatically cleaned up by a custom system.
+    ///
+    /// When the last copy of the returned signal is dropped, `cleanup` will be run in [`AutoDespawnSet`] with the
+    /// entity as input. The system is responsible for despawning the entity if needed. It will not run if the entity
+    /// was already despawned.
+    #[track_caller]
+    pub fn prepare_with<Marker>(
+        &self,
+        entity  : Entity,
+        cleanup : impl IntoSystem<Entity, (), Marker> + Send + Sync + 'static
+    ) -> AutoDespawnSignal
+    {
+        self.signal(entity, DespawnPolicy::Custom(CallbackSystem::new(cleanup)))
+    }
+
+    fn try_recv(&self) -> Option<(Entity, DespawnPolicy)>
     {
          self.receiver.try_recv()
     }
@@ -73,19 +307,27 @@ impl AutoDespawner
 
 /// RAII handle to a despawn signal.
 ///
-/// The signal can be cloned. When the last copy is dropped, the entity will be despawned in the `Last` schedule.
+/// The signal can be cloned. When the last copy is dropped, the entity will be despawned (or cleaned up according to
+/// the policy chosen in [`AutoDespawner`]) in [`AutoDespawnSet`].
 pub struct AutoDespawnSignal(Arc<AutoDespawnSignalInner>);
 
 impl AutoDespawnSignal
 {
-    fn new(entity: Entity, sender: Sender<Entity>) -> Self
+    pub fn entity(&self) -> Entity
     {
-        Self(Arc::new(AutoDespawnSignalInner{ entity, sender }))
+        self.0.entity
     }
 
-    pub fn entity(&self) -> Entity
+    /// Make a weak handle to this signal. Weak handles don't keep the entity alive.
+    pub fn downgrade(&self) -> WeakAutoDespawnSignal
     {
-        self.0.entity
+        WeakAutoDespawnSignal{ entity: self.0.entity, inner: Arc::downgrade(&self.0) }
+    }
+
+    /// Get the number of live copies of this signal.
+    pub fn strong_count(&self) -> usize
+    {
+        Arc::strong_count(&self.0)
     }
 }
 
@@ -96,11 +338,65 @@ impl Clone for AutoDespawnSignal
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Extends the `App` API with a method to set up auto despawning.
+/// Weak handle to an [`AutoDespawnSignal`].
+///
+/// Can be used to check if a signal is still alive without keeping its entity alive.
+#[derive(Clone)]
+pub struct WeakAutoDespawnSignal
+{
+    entity : Entity,
+    inner  : Weak<AutoDespawnSignalInner>,
+}
+
+impl WeakAutoDespawnSignal
+{
+    pub fn entity(&self) -> Entity
+    {
+        self.entity
+    }
+
+    /// Get a strong copy of the signal if it is still alive.
+    pub fn upgrade(&self) -> Option<AutoDespawnSignal>
+    {
+        self.inner.upgrade().map(AutoDespawnSignal)
+    }
+
+    /// Check if any strong copies of the signal are alive.
+    pub fn is_alive(&self) -> bool
+    {
+        self.inner.strong_count() > 0
+    }
+
+    /// Get the number of live strong copies of the signal.
+    pub fn strong_count(&self) -> usize
+    {
+        self.inner.strong_count()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends the `App` API with methods to set up auto despawning.
 pub trait AutoDespawnAppExt
 {
-    /// Set up auto despawning. Can be added to multiple plugins without conflict.
+    /// Set up auto despawning in [`AutoDespawnSet`] in the `Last` schedule. Can be added to multiple plugins without
+    /// conflict.
     fn setup_auto_despawn(&mut self) -> &mut Self;
+
+    /// Also despawn entities in [`AutoDespawnSet`] in `schedule`. Sets up auto despawning if necessary.
+    ///
+    /// Use this if dropped signals should be handled earlier in the frame than `Last`. Adding a schedule multiple times
+    /// has no effect.
+    fn add_auto_despawn_schedule(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+
+    /// Despawn entities in [`AutoDespawnSet`] in `schedule` only, replacing `Last` and any schedules added with
+    /// [`Self::add_auto_despawn_schedule()`]. Sets up auto despawning if necessary.
+    fn set_auto_despawn_schedule(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+
+    /// Log entity and policy info when [`AutoDespawnSignal`]s are dropped. Sets up auto despawning if necessary.
+    ///
+    /// See [`AutoDespawner::set_drop_logging()`].
+    fn log_auto_despawn_signals(&mut self, enabled: bool) -> &mut Self;
 }
 
 impl AutoDespawnAppExt for App
@@ -109,7 +405,43 @@ impl AutoDespawnAppExt for App
     {
         if self.world.contains_resource::<AutoDespawner>() { return self; }
         self.insert_resource(AutoDespawner::new())
-            .add_systems(Last, auto_despawn)
+            .init_resource::<AutoDespawnSchedules>()
+            .init_resource::<AutoDespawnDiagnostics>()
+            .add_auto_despawn_schedule(Last)
+    }
+
+    fn add_auto_despawn_schedule(&mut self, schedule: impl ScheduleLabel) -> &mut Self
+    {
+        self.setup_auto_despawn();
+
+        let label = schedule.intern();
+        let mut schedules = self.world.resource_mut::<AutoDespawnSchedules>();
+        if schedules.active.contains(&label) { return self; }
+        schedules.active.push(label);
+
+        // add the flush system the first time the schedule is used
+        if schedules.registered.contains(&label) { return self; }
+        schedules.registered.push(label);
+
+        self.add_systems(label,
+                flush_auto_despawns
+                    .run_if(auto_despawn_schedule_is_active(label))
+                    .in_set(AutoDespawnSet)
+            )
+    }
+
+    fn set_auto_despawn_schedule(&mut self, schedule: impl ScheduleLabel) -> &mut Self
+    {
+        self.setup_auto_despawn();
+        self.world.resource_mut::<AutoDespawnSchedules>().active.clear();
+        self.add_auto_despawn_schedule(schedule)
+    }
+
+    fn log_auto_despawn_signals(&mut self, enabled: bool) -> &mut Self
+    {
+        self.setup_auto_despawn();
+        self.world.resource::<AutoDespawner>().set_drop_logging(enabled);
+        self
     }
 }
 
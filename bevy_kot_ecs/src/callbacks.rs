@@ -6,10 +6,46 @@ use bevy::prelude::*;
 
 //standard shortcuts
 use std::marker::PhantomData;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// The function invoked by a [`Callback`].
+#[derive(Clone)]
+enum CallbackInner
+{
+    Closure(Arc<dyn Fn(&mut World) + Send + Sync + 'static>),
+    System(Arc<Mutex<CallbackSystem<Entity, ()>>>),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Run a system callback that may be shared between clones of a callback.
+///
+/// Returns `false` if the system is already running (the recursive call does nothing).
+fn run_shared_system<I: Send + Sync + 'static>(
+    shared : &Mutex<CallbackSystem<I, ()>>,
+    world  : &mut World,
+    input  : I
+) -> bool
+{
+    // take the system out so it can be run without holding the lock
+    let Ok(mut guard) = shared.lock() else { return false; };
+    let mut system = std::mem::take(&mut *guard);
+    drop(guard);
+    if system.is_empty() { return false; }
+
+    let _ = system.run(world, input);
+
+    let Ok(mut guard) = shared.lock() else { return true; };
+    *guard = system;
+    true
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Callback wrapper for FnOnce functions. Implements `Command`.
 /// - The type `T` can be used to mark the callback for query filtering.
 #[derive(Component)]
@@ -88,12 +124,16 @@ impl<T: Send + Sync + 'static, C: Send + Sync + 'static> Command for CallwithOnc
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Callback wrapper. Implements `Command`.
+/// Callback wrapper. The helper returned by `.call_on()` implements `Command`.
 /// - The type `T` can be used to mark the callback for query filtering.
+///
+/// Callbacks are either closures (see [`Callback::new()`]) or systems that receive the entity that owns the callback
+/// as input (see [`Callback::from_system()`]). Invoke callbacks on entities with [`try_callback()`] or
+/// [`Callback::call_on()`].
 #[derive(Component)]
 pub struct Callback<T: Send + Sync + 'static>
 {
-    callback : Arc<dyn Fn(&mut World) -> () + Send + Sync + 'static>,
+    callback : CallbackInner,
     _phantom : PhantomData<T>,
 }
 
@@ -102,17 +142,67 @@ impl<T: Send + Sync + 'static> Clone for Callback<T>
 
 impl<T: Send + Sync + 'static> Callback<T>
 {
+    /// Make a closure-based callback.
     pub fn new(callback: impl Fn(&mut World) -> () + Send + Sync + 'static) -> Self
     {
-        Self{ callback: Arc::new(callback), _phantom: PhantomData::default() }
+        Self{ callback: CallbackInner::Closure(Arc::new(callback)), _phantom: PhantomData::default() }
+    }
+
+    /// Make a system-based callback. The system receives the entity that owns the callback as input.
+    ///
+    /// Unlike closure-based callbacks, the system can have `Local` state and normal system parameters. Clones of the
+    /// callback share the same system.
+    pub fn from_system<Marker>(system: impl IntoSystem<Entity, (), Marker> + Send + Sync + 'static) -> Self
+    {
+        Self{
+            callback : CallbackInner::System(Arc::new(Mutex::new(CallbackSystem::new(system)))),
+            _phantom : PhantomData
+        }
+    }
+
+    /// Check if the callback is system-based.
+    pub fn is_system(&self) -> bool
+    {
+        matches!(self.callback, CallbackInner::System(_))
+    }
+
+    /// Invoke the callback for `entity` (the entity that owns the callback).
+    ///
+    /// Returns `false` if a system-based callback is invoked recursively (the recursive call does nothing).
+    pub fn call(&self, world: &mut World, entity: Entity) -> bool
+    {
+        match &self.callback
+        {
+            CallbackInner::Closure(callback) =>
+            {
+                (callback)(world);
+                true
+            }
+            CallbackInner::System(shared) => run_shared_system(shared, world, entity),
+        }
     }
+
+    /// Make a command that invokes the callback for `entity`.
+    pub fn call_on(&self, entity: Entity) -> CallbackOn<T>
+    {
+        CallbackOn{ callback: self.clone(), entity }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Callback wrapper with the entity that owns the callback baked in. Implements `Command`.
+pub struct CallbackOn<T: Send + Sync + 'static>
+{
+    callback : Callback<T>,
+    entity   : Entity,
 }
 
-impl<T: Send + Sync + 'static> Command for Callback<T>
+impl<T: Send + Sync + 'static> Command for CallbackOn<T>
 {
     fn apply(self, world: &mut World)
     {
-        (self.callback)(world);
+        let _ = self.callback.call(world, self.entity);
     }
 }
 
@@ -137,6 +227,16 @@ impl<T: Send + Sync + 'static, V: Send + Sync + 'static> CallbackWith<T, V>
         Self{ callback: Arc::new(callback), _phantom: PhantomData::default() }
     }
 
+    /// Make a system-based callback. The system receives the call value as input.
+    ///
+    /// The system can have `Local` state and normal system parameters. Clones of the callback share the same system,
+    /// and recursive calls do nothing.
+    pub fn from_system<Marker>(system: impl IntoSystem<V, (), Marker> + Send + Sync + 'static) -> Self
+    {
+        let shared = Mutex::new(CallbackSystem::new(system));
+        Self::new(move |world: &mut World, call_value: V| { let _ = run_shared_system(&shared, world, call_value); })
+    }
+
     pub fn call_with(&self, call_value: V) -> Callwith<T, V>
     {
         Callwith{ callback: self.callback.clone(), call_value, _phantom: PhantomData::default() }
@@ -295,15 +395,105 @@ where
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Try to invoke the callback `C` on `entity`.
+/// Try to invoke the callback `C` on `entity`. System-based callbacks receive `entity` as input.
 ///
-/// Returns `false` if the entity doesn't exist or the callback is not present on the entity.
+/// Returns `false` if the entity doesn't exist or the callback is not present on the entity. Also returns `false` if
+/// a system-based callback is invoked recursively.
 pub fn try_callback<C: Send + Sync + 'static>(world: &mut World, entity: Entity) -> bool
 {
-    let Some(entity_mut) = world.get_entity_mut(entity) else { return false; };
-    let Some(cb) = entity_mut.get::<Callback<C>>() else { return false; };
-    cb.clone().apply(world);
-    true
+    let Some(entity_ref) = world.get_entity(entity) else { return false; };
+    let Some(cb) = entity_ref.get::<Callback<C>>() else { return false; };
+    cb.clone().call(world, entity)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks a callback that is bubbling up an entity hierarchy (see [`bubble_callback()`]).
+///
+/// Available as a resource while bubbling handlers run.
+#[derive(Resource, Debug, Copy, Clone)]
+pub struct CallbackBubble
+{
+    target  : Entity,
+    current : Entity,
+    stopped : bool,
+}
+
+impl CallbackBubble
+{
+    /// The entity the callback was originally invoked on.
+    pub fn target(&self) -> Entity
+    {
+        self.target
+    }
+
+    /// The entity whose handler is currently running.
+    pub fn current(&self) -> Entity
+    {
+        self.current
+    }
+
+    /// Stop the callback from propagating to the current entity's ancestors.
+    pub fn stop_propagation(&mut self)
+    {
+        self.stopped = true;
+    }
+
+    /// Check if propagation was stopped.
+    pub fn is_stopped(&self) -> bool
+    {
+        self.stopped
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Invoke the callback `C` on `target`, then on each of its ancestors (following [`Parent`]) until a handler stops
+/// propagation or the root is reached.
+///
+/// Handlers are invoked with [`try_callback()`], and can access the [`CallbackBubble`] resource to inspect the original
+/// target or call [`CallbackBubble::stop_propagation()`]. Entities without the callback are skipped. Each entity's
+/// parent is looked up before its handler runs, so handlers may despawn their own entity.
+///
+/// Returns the number of handlers invoked.
+pub fn bubble_callback<C: Send + Sync + 'static>(world: &mut World, target: Entity) -> usize
+{
+    // save the outer bubble in case we are bubbling recursively
+    let outer = world.remove_resource::<CallbackBubble>();
+
+    let mut count = 0;
+    let mut next = Some(target);
+    while let Some(current) = next
+    {
+        next = world.get::<Parent>(current).map(|parent| parent.get());
+
+        world.insert_resource(CallbackBubble{ target, current, stopped: false });
+        if !try_callback::<C>(world, current) { continue; }
+        count += 1;
+
+        let Some(bubble) = world.get_resource::<CallbackBubble>() else { break; };
+        if bubble.is_stopped() { break; }
+    }
+
+    // restore the outer bubble
+    match outer
+    {
+        Some(outer) => world.insert_resource(outer),
+        None        => { let _ = world.remove_resource::<CallbackBubble>(); }
+    }
+
+    count
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stop the callback that is currently bubbling from propagating further.
+///
+/// Does nothing if no callback is bubbling. See [`bubble_callback()`].
+pub fn stop_callback_propagation(world: &mut World)
+{
+    let Some(mut bubble) = world.get_resource_mut::<CallbackBubble>() else { return; };
+    bubble.stop_propagation();
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -323,3 +513,294 @@ where
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+type CallbackHandler = Arc<dyn Fn(&mut World) + Send + Sync + 'static>;
+type CallbackHandlerWith<V> = Arc<dyn Fn(&mut World, V) + Send + Sync + 'static>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Identifies a handler in a [`CallbackList`] or [`CallbackListWith`].
+///
+/// Ids are globally unique, so they can be allocated before a handler is inserted (e.g. when adding handlers via
+/// [`CallbackCommandsExt`]).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct CallbackId(u64);
+
+impl CallbackId
+{
+    /// Allocate a new unique id.
+    pub fn new() -> Self
+    {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        Self(COUNTER.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for CallbackId
+{
+    fn default() -> Self { Self::new() }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A list of callbacks. Handlers are invoked in the order they were added.
+/// - The type `T` can be used to mark the callback list for query filtering (e.g. `CallbackList<OnClick>`).
+#[derive(Component)]
+pub struct CallbackList<T: Send + Sync + 'static>
+{
+    handlers : Vec<(CallbackId, CallbackHandler)>,
+    _phantom : PhantomData<T>,
+}
+
+impl<T: Send + Sync + 'static> CallbackList<T>
+{
+    /// Add a handler to the end of the list.
+    pub fn add(&mut self, handler: impl Fn(&mut World) + Send + Sync + 'static) -> CallbackId
+    {
+        let id = CallbackId::new();
+        self.add_with_id(id, handler);
+        id
+    }
+
+    /// Add a handler with a pre-allocated id to the end of the list.
+    pub fn add_with_id(&mut self, id: CallbackId, handler: impl Fn(&mut World) + Send + Sync + 'static)
+    {
+        self.handlers.push((id, Arc::new(handler)));
+    }
+
+    /// Remove a handler.
+    ///
+    /// Returns `false` if the handler is not in the list.
+    pub fn remove(&mut self, id: CallbackId) -> bool
+    {
+        let Some(position) = self.handlers.iter().position(|(handler_id, _)| *handler_id == id) else { return false; };
+        let _ = self.handlers.remove(position);
+        true
+    }
+
+    /// Check if the list contains a handler.
+    pub fn contains(&self, id: CallbackId) -> bool
+    {
+        self.handlers.iter().any(|(handler_id, _)| *handler_id == id)
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.handlers.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.handlers.is_empty()
+    }
+}
+
+impl<T: Send + Sync + 'static> Default for CallbackList<T>
+{
+    fn default() -> Self { Self{ handlers: Vec::default(), _phantom: PhantomData } }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A list of callbacks that are called with a value. Handlers are invoked in the order they were added, and each
+/// handler receives a clone of the value.
+/// - The type `T` can be used to mark the callback list for query filtering.
+#[derive(Component)]
+pub struct CallbackListWith<T: Send + Sync + 'static, V>
+{
+    handlers : Vec<(CallbackId, CallbackHandlerWith<V>)>,
+    _phantom : PhantomData<T>,
+}
+
+impl<T: Send + Sync + 'static, V: Send + Sync + 'static> CallbackListWith<T, V>
+{
+    /// Add a handler to the end of the list.
+    pub fn add(&mut self, handler: impl Fn(&mut World, V) + Send + Sync + 'static) -> CallbackId
+    {
+        let id = CallbackId::new();
+        self.add_with_id(id, handler);
+        id
+    }
+
+    /// Add a handler with a pre-allocated id to the end of the list.
+    pub fn add_with_id(&mut self, id: CallbackId, handler: impl Fn(&mut World, V) + Send + Sync + 'static)
+    {
+        self.handlers.push((id, Arc::new(handler)));
+    }
+
+    /// Remove a handler.
+    ///
+    /// Returns `false` if the handler is not in the list.
+    pub fn remove(&mut self, id: CallbackId) -> bool
+    {
+        let Some(position) = self.handlers.iter().position(|(handler_id, _)| *handler_id == id) else { return false; };
+        let _ = self.handlers.remove(position);
+        true
+    }
+
+    /// Check if the list contains a handler.
+    pub fn contains(&self, id: CallbackId) -> bool
+    {
+        self.handlers.iter().any(|(handler_id, _)| *handler_id == id)
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.handlers.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.handlers.is_empty()
+    }
+}
+
+impl<T: Send + Sync + 'static, V> Default for CallbackListWith<T, V>
+{
+    fn default() -> Self { Self{ handlers: Vec::default(), _phantom: PhantomData } }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Add a handler to the callback list `C` on `entity`. The list will be inserted if missing.
+///
+/// Returns `None` if the entity doesn't exist.
+pub fn add_callback<C: Send + Sync + 'static>(
+    world   : &mut World,
+    entity  : Entity,
+    handler : impl Fn(&mut World) + Send + Sync + 'static
+) -> Option<CallbackId>
+{
+    let mut entity_mut = world.get_entity_mut(entity)?;
+    if !entity_mut.contains::<CallbackList<C>>() { entity_mut.insert(CallbackList::<C>::default()); }
+    Some(entity_mut.get_mut::<CallbackList<C>>()?.add(handler))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Add a handler to the callback list `C` with value `V` on `entity`. The list will be inserted if missing.
+///
+/// Returns `None` if the entity doesn't exist.
+pub fn add_callback_with<C, V>(
+    world   : &mut World,
+    entity  : Entity,
+    handler : impl Fn(&mut World, V) + Send + Sync + 'static
+) -> Option<CallbackId>
+where
+    C: Send + Sync + 'static,
+    V: Send + Sync + 'static
+{
+    let mut entity_mut = world.get_entity_mut(entity)?;
+    if !entity_mut.contains::<CallbackListWith<C, V>>() { entity_mut.insert(CallbackListWith::<C, V>::default()); }
+    Some(entity_mut.get_mut::<CallbackListWith<C, V>>()?.add(handler))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Remove a handler from the callback list `C` on `entity`.
+///
+/// Returns `false` if the entity, list, or handler doesn't exist.
+pub fn remove_callback<C: Send + Sync + 'static>(world: &mut World, entity: Entity, id: CallbackId) -> bool
+{
+    let Some(mut entity_mut) = world.get_entity_mut(entity) else { return false; };
+    let Some(mut list) = entity_mut.get_mut::<CallbackList<C>>() else { return false; };
+    list.remove(id)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Remove a handler from the callback list `C` with value `V` on `entity`.
+///
+/// Returns `false` if the entity, list, or handler doesn't exist.
+pub fn remove_callback_with<C, V>(world: &mut World, entity: Entity, id: CallbackId) -> bool
+where
+    C: Send + Sync + 'static,
+    V: Send + Sync + 'static
+{
+    let Some(mut entity_mut) = world.get_entity_mut(entity) else { return false; };
+    let Some(mut list) = entity_mut.get_mut::<CallbackListWith<C, V>>() else { return false; };
+    list.remove(id)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Try to invoke all handlers in the callback list `C` on `entity`, in order.
+///
+/// The list is not removed from the entity while handlers run. Handlers added or removed by other handlers will take
+/// effect the next time the list is invoked.
+///
+/// Returns the number of handlers invoked.
+pub fn try_callbacks<C: Send + Sync + 'static>(world: &mut World, entity: Entity) -> usize
+{
+    let Some(entity_ref) = world.get_entity(entity) else { return 0; };
+    let Some(list) = entity_ref.get::<CallbackList<C>>() else { return 0; };
+    let handlers: Vec<_> = list.handlers.iter().map(|(_, handler)| handler.clone()).collect();
+
+    for handler in handlers.iter() { (handler)(world); }
+    handlers.len()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Try to invoke all handlers in the callback list `C` on `entity` with `value`, in order.
+///
+/// See [`try_callbacks()`].
+pub fn try_callbacks_with<C, V>(world: &mut World, entity: Entity, value: V) -> usize
+where
+    C: Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static
+{
+    let Some(entity_ref) = world.get_entity(entity) else { return 0; };
+    let Some(list) = entity_ref.get::<CallbackListWith<C, V>>() else { return 0; };
+    let handlers: Vec<_> = list.handlers.iter().map(|(_, handler)| handler.clone()).collect();
+
+    for handler in handlers.iter() { (handler)(world, value.clone()); }
+    handlers.len()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends the `Commands` API with callback list management.
+pub trait CallbackCommandsExt
+{
+    /// Schedule a handler to be added to the callback list `C` on `entity`.
+    ///
+    /// Returns the id the handler will have. Nothing happens if the entity doesn't exist when the command is applied.
+    fn add_callback<C: Send + Sync + 'static>(
+        &mut self,
+        entity  : Entity,
+        handler : impl Fn(&mut World) + Send + Sync + 'static
+    ) -> CallbackId;
+
+    /// Schedule a handler to be removed from the callback list `C` on `entity`.
+    fn remove_callback<C: Send + Sync + 'static>(&mut self, entity: Entity, id: CallbackId);
+}
+
+impl<'w, 's> CallbackCommandsExt for Commands<'w, 's>
+{
+    fn add_callback<C: Send + Sync + 'static>(
+        &mut self,
+        entity  : Entity,
+        handler : impl Fn(&mut World) + Send + Sync + 'static
+    ) -> CallbackId
+    {
+        let id = CallbackId::new();
+        self.add(
+                move |world: &mut World|
+                {
+                    let Some(mut entity_mut) = world.get_entity_mut(entity) else { return; };
+                    if !entity_mut.contains::<CallbackList<C>>() { entity_mut.insert(CallbackList::<C>::default()); }
+                    let Some(mut list) = entity_mut.get_mut::<CallbackList<C>>() else { return; };
+                    list.add_with_id(id, handler);
+                }
+            );
+        id
+    }
+
+    fn remove_callback<C: Send + Sync + 'static>(&mut self, entity: Entity, id: CallbackId)
+    {
+        self.add(move |world: &mut World| { let _ = remove_callback::<C>(world, entity, id); });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -6,6 +6,75 @@ use bevy::prelude::*;
 //standard shortcuts
 
 
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Insert bundles on entities that are known to exist.
+/// - Entities that move to the same archetype share cached archetype lookups.
+fn insert_bundle_batch<B: Bundle>(world: &mut World, batch: Vec<(Entity, B)>)
+{
+    if batch.is_empty() { return; }
+    let _ = world.insert_or_spawn_batch(batch);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A bundle of comparable components, used by [`try_set_bundle()`] and [`try_update_bundle_if_different()`].
+///
+/// Implemented for tuples of up to 12 components that implement `Eq`. Use a 1-tuple for single components.
+pub trait EqBundle: Bundle
+{
+    /// Check if the entity has all of the bundle's components.
+    fn contained_in(entity_mut: &EntityWorldMut) -> bool;
+
+    /// Update the bundle's components on the entity if their values differ.
+    ///
+    /// Returns `true` if any component was updated. Components that don't exist on the entity are ignored.
+    fn update_if_different(self, entity_mut: &mut EntityWorldMut) -> bool;
+}
+
+macro_rules! impl_eq_bundle
+{
+    ($($c:ident),+) =>
+    {
+        impl<$($c: Component + Eq),+> EqBundle for ($($c,)+)
+        {
+            fn contained_in(entity_mut: &EntityWorldMut) -> bool
+            {
+                $(entity_mut.contains::<$c>())&&+
+            }
+
+            #[allow(non_snake_case)]
+            fn update_if_different(self, entity_mut: &mut EntityWorldMut) -> bool
+            {
+                let ($($c,)+) = self;
+                let mut updated = false;
+                $(
+                    if let Some(mut existing) = entity_mut.get_mut::<$c>()
+                    {
+                        if *(existing.bypass_change_detection()) != $c { *existing = $c; updated = true; }
+                    }
+                )+
+                updated
+            }
+        }
+    };
+}
+
+impl_eq_bundle!(C1);
+impl_eq_bundle!(C1, C2);
+impl_eq_bundle!(C1, C2, C3);
+impl_eq_bundle!(C1, C2, C3, C4);
+impl_eq_bundle!(C1, C2, C3, C4, C5);
+impl_eq_bundle!(C1, C2, C3, C4, C5, C6);
+impl_eq_bundle!(C1, C2, C3, C4, C5, C6, C7);
+impl_eq_bundle!(C1, C2, C3, C4, C5, C6, C7, C8);
+impl_eq_bundle!(C1, C2, C3, C4, C5, C6, C7, C8, C9);
+impl_eq_bundle!(C1, C2, C3, C4, C5, C6, C7, C8, C9, C10);
+impl_eq_bundle!(C1, C2, C3, C4, C5, C6, C7, C8, C9, C10, C11);
+impl_eq_bundle!(C1, C2, C3, C4, C5, C6, C7, C8, C9, C10, C11, C12);
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Try to add a component to an entity.
@@ -70,3 +139,196 @@ pub fn try_update_component_if_different<C: Component + Eq>(world: &mut World, e
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+/// Try to insert a bundle on an entity.
+///
+/// All of the bundle's components are inserted with one archetype move. Existing components are overwritten.
+///
+/// Returns `true` if the entity exists.
+pub fn try_insert_bundle<B: Bundle>(world: &mut World, entity: Entity, bundle: B) -> bool
+{
+    let Some(mut entity_mut) = world.get_entity_mut(entity) else { return false; };
+    entity_mut.insert(bundle);
+    true
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Try to remove a bundle from an entity.
+///
+/// Returns `Some([bundle])` if the entity had all of the bundle's components. Otherwise nothing is removed.
+pub fn try_remove_bundle<B: Bundle>(world: &mut World, entity: Entity) -> Option<B>
+{
+    world.get_entity_mut(entity)?.take::<B>()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Try to set the values of a bundle's components on an entity.
+///
+/// Returns `true` if any components were added-to or updated on the entity.
+/// - If the entity has all of the bundle's components, only components with new values are updated (and trigger change
+///   detection).
+/// - Otherwise the entire bundle is inserted with one archetype move, which triggers change detection for all of the
+///   bundle's components.
+pub fn try_set_bundle<B: EqBundle>(world: &mut World, entity: Entity, bundle: B) -> bool
+{
+    let Some(mut entity_mut) = world.get_entity_mut(entity) else { return false; };
+    if !B::contained_in(&entity_mut) { entity_mut.insert(bundle); return true; }
+    bundle.update_if_different(&mut entity_mut)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Try to update the values of a bundle's components on an entity.
+///
+/// Returns `true` if any component was updated on the entity with a different value.
+/// - Returns `false` without updating anything if any of the bundle's components did not previously exist on the
+///   entity, or if no values would change.
+/// - Does not trigger change detection for components that aren't modified.
+pub fn try_update_bundle_if_different<B: EqBundle>(world: &mut World, entity: Entity, bundle: B) -> bool
+{
+    let Some(mut entity_mut) = world.get_entity_mut(entity) else { return false; };
+    if !B::contained_in(&entity_mut) { return false; }
+    bundle.update_if_different(&mut entity_mut)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Try to insert bundles on many entities. See [`try_insert_bundle()`].
+///
+/// Bundles are inserted in one batch, which is faster than inserting them one at a time.
+///
+/// Returns the number of entities that exist.
+pub fn try_insert_bundle_batch<B: Bundle>(world: &mut World, batch: impl IntoIterator<Item = (Entity, B)>) -> usize
+{
+    let batch: Vec<(Entity, B)> = batch
+        .into_iter()
+        .filter(|(entity, _)| world.get_entity(*entity).is_some())
+        .collect();
+    let count = batch.len();
+    insert_bundle_batch(world, batch);
+
+    count
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Try to set the value of a component on many entities. See [`try_set_component()`].
+///
+/// Components that are added to entities are inserted in one batch (see [`try_insert_bundle_batch()`]).
+///
+/// Returns the number of entities the component was added-to or updated on.
+pub fn try_set_component_batch<C: Component + Eq>(
+    world : &mut World,
+    batch : impl IntoIterator<Item = (Entity, C)>
+) -> usize
+{
+    try_set_bundle_batch(world, batch.into_iter().map(|(entity, component)| (entity, (component,))))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Try to set the values of a bundle's components on many entities. See [`try_set_bundle()`].
+///
+/// Bundles that are inserted on entities missing any of the bundle's components are inserted in one batch (see
+/// [`try_insert_bundle_batch()`]).
+///
+/// Returns the number of entities that had components added-to or updated.
+pub fn try_set_bundle_batch<B: EqBundle>(world: &mut World, batch: impl IntoIterator<Item = (Entity, B)>) -> usize
+{
+    let mut count = 0;
+    let mut inserts = Vec::new();
+
+    for (entity, bundle) in batch
+    {
+        let Some(mut entity_mut) = world.get_entity_mut(entity) else { continue; };
+        if !B::contained_in(&entity_mut) { inserts.push((entity, bundle)); continue; }
+        if bundle.update_if_different(&mut entity_mut) { count += 1; }
+    }
+
+    count += inserts.len();
+    insert_bundle_batch(world, inserts);
+
+    count
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends the `Commands` API with deferred component utilities.
+///
+/// Nothing happens for entities that don't exist when the commands are applied.
+pub trait ComponentUtilsCommandsExt
+{
+    /// Schedule [`try_insert_bundle()`].
+    fn try_insert_bundle<B: Bundle>(&mut self, entity: Entity, bundle: B);
+
+    /// Schedule [`try_set_component()`].
+    fn try_set_component<C: Component + Eq>(&mut self, entity: Entity, component: C);
+
+    /// Schedule [`try_update_component_if_different()`].
+    fn try_update_component_if_different<C: Component + Eq>(&mut self, entity: Entity, component: C);
+
+    /// Schedule [`try_set_bundle()`].
+    fn try_set_bundle<B: EqBundle>(&mut self, entity: Entity, bundle: B);
+
+    /// Schedule [`try_update_bundle_if_different()`].
+    fn try_update_bundle_if_different<B: EqBundle>(&mut self, entity: Entity, bundle: B);
+
+    /// Schedule [`try_insert_bundle_batch()`].
+    fn try_insert_bundle_batch<B: Bundle>(&mut self, batch: impl IntoIterator<Item = (Entity, B)>);
+
+    /// Schedule [`try_set_component_batch()`].
+    fn try_set_component_batch<C: Component + Eq>(&mut self, batch: impl IntoIterator<Item = (Entity, C)>);
+
+    /// Schedule [`try_set_bundle_batch()`].
+    fn try_set_bundle_batch<B: EqBundle>(&mut self, batch: impl IntoIterator<Item = (Entity, B)>);
+}
+
+impl<'w, 's> ComponentUtilsCommandsExt for Commands<'w, 's>
+{
+    fn try_insert_bundle<B: Bundle>(&mut self, entity: Entity, bundle: B)
+    {
+        self.add(move |world: &mut World| { try_insert_bundle(world, entity, bundle); });
+    }
+
+    fn try_set_component<C: Component + Eq>(&mut self, entity: Entity, component: C)
+    {
+        self.add(move |world: &mut World| { try_set_component(world, entity, component); });
+    }
+
+    fn try_update_component_if_different<C: Component + Eq>(&mut self, entity: Entity, component: C)
+    {
+        self.add(move |world: &mut World| { try_update_component_if_different(world, entity, component); });
+    }
+
+    fn try_set_bundle<B: EqBundle>(&mut self, entity: Entity, bundle: B)
+    {
+        self.add(move |world: &mut World| { try_set_bundle(world, entity, bundle); });
+    }
+
+    fn try_update_bundle_if_different<B: EqBundle>(&mut self, entity: Entity, bundle: B)
+    {
+        self.add(move |world: &mut World| { try_update_bundle_if_different(world, entity, bundle); });
+    }
+
+    fn try_insert_bundle_batch<B: Bundle>(&mut self, batch: impl IntoIterator<Item = (Entity, B)>)
+    {
+        let batch: Vec<(Entity, B)> = batch.into_iter().collect();
+        self.add(move |world: &mut World| { try_insert_bundle_batch(world, batch); });
+    }
+
+    fn try_set_component_batch<C: Component + Eq>(&mut self, batch: impl IntoIterator<Item = (Entity, C)>)
+    {
+        let batch: Vec<(Entity, C)> = batch.into_iter().collect();
+        self.add(move |world: &mut World| { try_set_component_batch(world, batch); });
+    }
+
+    fn try_set_bundle_batch<B: EqBundle>(&mut self, batch: impl IntoIterator<Item = (Entity, B)>)
+    {
+        let batch: Vec<(Entity, B)> = batch.into_iter().collect();
+        self.add(move |world: &mut World| { try_set_bundle_batch(world, batch); });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
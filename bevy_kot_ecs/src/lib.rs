@@ -1,18 +1,27 @@
 //documentation
 #![doc = include_str!("../README.md")]
 
+//features
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
 //module tree
 mod auto_despawn;
 mod callbacks;
 mod component_utils;
+mod ownership;
 mod react;
+mod system_batch;
 mod system_callers;
+mod system_pipes;
 
 //API exports
 pub use crate::auto_despawn::*;
 pub use crate::callbacks::*;
 pub use crate::component_utils::*;
+pub use crate::ownership::*;
 pub use crate::react::*;
+pub use crate::system_batch::*;
 pub use crate::system_callers::*;
+pub use crate::system_pipes::*;
 
 pub use bevy_kot_derive::*;
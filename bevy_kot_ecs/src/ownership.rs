@@ -0,0 +1,193 @@
+//local shortcuts
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Check if an entity has live owners.
+pub(crate) fn entity_is_owned(world: &World, entity: Entity) -> bool
+{
+    world.get::<Owned>(entity).is_some_and(|owned| owned.signal.is_alive())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component that keeps entities alive by holding their [`AutoDespawnSignal`]s.
+///
+/// When this component is removed or its entity is despawned, the signals are dropped and owned entities with no other
+/// owners are despawned by the [`AutoDespawner`]. Since the despawner handles signals dropped while it is despawning,
+/// a chain of owned entities collapses in one flush.
+///
+/// Ownership cycles are never despawned.
+#[derive(Component, Default)]
+pub struct OwnedEntities
+{
+    signals: Vec<AutoDespawnSignal>,
+}
+
+impl OwnedEntities
+{
+    /// Add a signal. Does nothing if a signal for the same entity is already held.
+    pub fn add(&mut self, signal: AutoDespawnSignal)
+    {
+        if self.contains(signal.entity()) { return; }
+        self.signals.push(signal);
+    }
+
+    /// Remove the signal for `entity`.
+    pub fn remove(&mut self, entity: Entity) -> Option<AutoDespawnSignal>
+    {
+        let position = self.signals.iter().position(|signal| signal.entity() == entity)?;
+        Some(self.signals.remove(position))
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool
+    {
+        self.signals.iter().any(|signal| signal.entity() == entity)
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.signals.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.signals.is_empty()
+    }
+
+    /// Iterate over the owned entities.
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_
+    {
+        self.signals.iter().map(|signal| signal.entity())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component added to entities owned with [`own_entity()`].
+///
+/// Holds a weak copy of the entity's [`AutoDespawnSignal`] so all owners share the same signal.
+/// While the entity has owners, the [`AutoDespawner`] will not despawn it when a stale copy of its ownership signal is
+/// handled (e.g. if the entity was released by its last owner and then re-owned before the next auto despawn flush).
+/// Other signals prepared for the entity are unaffected.
+#[derive(Component, Clone)]
+pub struct Owned
+{
+    signal: WeakAutoDespawnSignal,
+}
+
+impl Owned
+{
+    /// Get a strong copy of the entity's signal if any owners remain.
+    pub fn signal(&self) -> Option<AutoDespawnSignal>
+    {
+        self.signal.upgrade()
+    }
+
+    /// Get the number of owners.
+    pub fn owner_count(&self) -> usize
+    {
+        self.signal.strong_count()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Make `owner` keep `owned` alive.
+///
+/// The owned entity is despawned once all of its owners have released it or been despawned (see [`OwnedEntities`]).
+/// Returns `false` if either entity doesn't exist or there is no [`AutoDespawner`] (see
+/// [`AutoDespawnAppExt::setup_auto_despawn()`]).
+pub fn own_entity(world: &mut World, owner: Entity, owned: Entity) -> bool
+{
+    if world.get_entity(owner).is_none() { return false; }
+    let Some(despawner) = world.get_resource::<AutoDespawner>().cloned() else { return false; };
+    let Some(mut owned_mut) = world.get_entity_mut(owned) else { return false; };
+
+    // share the signal of existing owners
+    let signal = match owned_mut.get::<Owned>().and_then(Owned::signal)
+    {
+        Some(signal) => signal,
+        None =>
+        {
+            let signal = despawner.prepare_owned(owned);
+            owned_mut.insert(Owned{ signal: signal.downgrade() });
+            signal
+        }
+    };
+
+    let mut owner_mut = world.entity_mut(owner);
+    match owner_mut.get_mut::<OwnedEntities>()
+    {
+        Some(mut owned_entities) => owned_entities.add(signal),
+        None => { owner_mut.insert(OwnedEntities{ signals: vec![signal] }); }
+    }
+
+    true
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Make `owner` stop keeping `owned` alive.
+///
+/// Returns `false` if `owner` does not own `owned`.
+pub fn release_entity(world: &mut World, owner: Entity, owned: Entity) -> bool
+{
+    let Some(mut owner_mut) = world.get_entity_mut(owner) else { return false; };
+    let Some(mut owned_entities) = owner_mut.get_mut::<OwnedEntities>() else { return false; };
+    owned_entities.remove(owned).is_some()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends the `Commands` API with entity ownership methods.
+pub trait OwnershipCommandsExt
+{
+    /// Schedule `owner` to take ownership of `owned`. See [`own_entity()`].
+    fn own_entity(&mut self, owner: Entity, owned: Entity);
+
+    /// Spawn an entity that will be owned by `owner`. See [`own_entity()`].
+    ///
+    /// The entity is despawned on the next auto despawn flush if `owner` doesn't exist when the command is applied.
+    fn spawn_owned(&mut self, owner: Entity, bundle: impl Bundle) -> Entity;
+
+    /// Schedule `owner` to release `owned`. See [`release_entity()`].
+    fn release_entity(&mut self, owner: Entity, owned: Entity);
+}
+
+impl<'w, 's> OwnershipCommandsExt for Commands<'w, 's>
+{
+    fn own_entity(&mut self, owner: Entity, owned: Entity)
+    {
+        self.add(move |world: &mut World| { own_entity(world, owner, owned); });
+    }
+
+    fn spawn_owned(&mut self, owner: Entity, bundle: impl Bundle) -> Entity
+    {
+        let owned = self.spawn(bundle).id();
+        self.add(
+                move |world: &mut World|
+                {
+                    if own_entity(world, owner, owned) { return; }
+                    let Some(despawner) = world.get_resource::<AutoDespawner>() else { return; };
+                    let _ = despawner.prepare(owned);
+                }
+            );
+        owned
+    }
+
+    fn release_entity(&mut self, owner: Entity, owned: Entity)
+    {
+        self.add(move |world: &mut World| { release_entity(world, owner, owned); });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -4,7 +4,14 @@ mod react_cache;
 mod react_commands;
 mod react_component;
 mod react_events;
+mod react_forwarding;
+mod react_history;
+#[cfg(feature = "persistence")]
+mod react_persistence;
 mod react_resource;
+mod react_stats;
+#[cfg(feature = "testing")]
+mod react_testing;
 mod reaction_trigger;
 mod reaction_triggers_impl;
 mod utils;
@@ -15,7 +22,16 @@ pub(crate) use crate::react::react_cache::*;
 pub use crate::react::react_commands::*;
 pub use crate::react::react_component::*;
 pub use crate::react::react_events::*;
+pub use crate::react::react_forwarding::*;
+pub use crate::react::react_history::*;
+#[cfg(feature = "persistence")]
+#[cfg_attr(docsrs, doc(cfg(feature = "persistence")))]
+pub use crate::react::react_persistence::*;
 pub use crate::react::react_resource::*;
+pub use crate::react::react_stats::*;
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub use crate::react::react_testing::*;
 pub use crate::react::reaction_trigger::*;
 pub use crate::react::reaction_triggers_impl::*;
 pub use crate::react::utils::*;
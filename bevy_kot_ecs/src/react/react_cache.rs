@@ -9,6 +9,7 @@ use bevy::utils::{HashMap, HashSet};
 
 //standard shortcuts
 use core::any::TypeId;
+use std::fmt::Debug;
 use std::vec::Vec;
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -162,6 +163,16 @@ pub(crate) struct ReactCache
 
     /// Data event reactors
     event_reactors: HashMap<TypeId, Vec<AutoDespawnSignal>>,
+
+    /// Mutation history of tracked resources and components
+    history: ReactHistory,
+
+    /// Type names of component/resource/event reactor targets (cached for diagnostics)
+    type_names: HashMap<TypeId, &'static str>,
+    /// Number of reactors on a single trigger target that will log a leak warning
+    reactor_warning_threshold: Option<usize>,
+    /// Trigger targets that already logged a leak warning (keyed by trigger kind and debug-formatted target)
+    warned_reactor_targets: HashSet<(ReactTriggerKind, String)>,
 }
 
 impl ReactCache
@@ -173,6 +184,61 @@ impl ReactCache
         counter
     }
 
+    pub(crate) fn history_mut(&mut self) -> &mut ReactHistory
+    {
+        &mut self.history
+    }
+
+    pub(crate) fn set_reactor_warning_threshold(&mut self, threshold: Option<usize>)
+    {
+        self.reactor_warning_threshold = threshold;
+    }
+
+    pub(crate) fn register_type_name<T: 'static>(&mut self)
+    {
+        self.type_names.entry(TypeId::of::<T>()).or_insert_with(std::any::type_name::<T>);
+    }
+
+    pub(crate) fn type_name(&self, type_id: TypeId) -> &'static str
+    {
+        self.type_names.get(&type_id).copied().unwrap_or("unknown")
+    }
+
+    /// Log a warning if a trigger target's reactor count exceeds the warning threshold.
+    /// - Each trigger target only logs one warning.
+    pub(crate) fn check_reactor_count(&mut self, kind: ReactTriggerKind, target: &dyn Debug, count: usize)
+    {
+        let Some(threshold) = self.reactor_warning_threshold else { return; };
+        if count <= threshold { return; }
+        if !self.warned_reactor_targets.insert((kind, format!("{:?}", target))) { return; }
+        tracing::warn!(?kind, ?target, count, threshold, "reactor count exceeded threshold, reactors may be leaking");
+    }
+
+    /// Add counts of all reactors stored in the cache.
+    /// - Entity-specific reactors are stored on entities, so they are not collected here.
+    pub(crate) fn collect_stats(&self, stats: &mut ReactStats)
+    {
+        for (type_id, reactors) in self.component_reactors.iter()
+        {
+            let name = self.type_name(*type_id);
+            stats.add(ReactTriggerKind::ComponentInsertion, Some(name), None, reactors.insertion_callbacks.len());
+            stats.add(ReactTriggerKind::ComponentMutation, Some(name), None, reactors.mutation_callbacks.len());
+            stats.add(ReactTriggerKind::ComponentRemoval, Some(name), None, reactors.removal_callbacks.len());
+        }
+        for (type_id, reactors) in self.resource_reactors.iter()
+        {
+            stats.add(ReactTriggerKind::ResourceMutation, Some(self.type_name(*type_id)), None, reactors.len());
+        }
+        for (type_id, reactors) in self.event_reactors.iter()
+        {
+            stats.add(ReactTriggerKind::Event, Some(self.type_name(*type_id)), None, reactors.len());
+        }
+        for (entity, reactors) in self.despawn_reactors.iter()
+        {
+            stats.add(ReactTriggerKind::Despawn, None, Some(*entity), reactors.len());
+        }
+    }
+
     pub(crate) fn despawn_sender(&self) -> Sender<Entity>
     {
         self.despawn_sender.clone()
@@ -198,33 +264,42 @@ impl ReactCache
 
     pub(crate) fn register_insertion_reactor<C: ReactComponent>(&mut self, sys_handle: &AutoDespawnSignal) -> ReactorType
     {
-        self.component_reactors
+        self.register_type_name::<C>();
+        let callbacks = &mut self.component_reactors
             .entry(TypeId::of::<C>())
             .or_default()
-            .insertion_callbacks
-            .push(sys_handle.clone());
+            .insertion_callbacks;
+        callbacks.push(sys_handle.clone());
+        let count = callbacks.len();
+        self.check_reactor_count(ReactTriggerKind::ComponentInsertion, &std::any::type_name::<C>(), count);
 
         ReactorType::ComponentInsertion(TypeId::of::<C>())
     }
 
     pub(crate) fn register_mutation_reactor<C: ReactComponent>(&mut self, sys_handle: &AutoDespawnSignal) -> ReactorType
     {
-        self.component_reactors
+        self.register_type_name::<C>();
+        let callbacks = &mut self.component_reactors
             .entry(TypeId::of::<C>())
             .or_default()
-            .mutation_callbacks
-            .push(sys_handle.clone());
+            .mutation_callbacks;
+        callbacks.push(sys_handle.clone());
+        let count = callbacks.len();
+        self.check_reactor_count(ReactTriggerKind::ComponentMutation, &std::any::type_name::<C>(), count);
 
         ReactorType::ComponentMutation(TypeId::of::<C>())
     }
 
     pub(crate) fn register_removal_reactor<C: ReactComponent>(&mut self, sys_handle: &AutoDespawnSignal) -> ReactorType
     {
-        self.component_reactors
+        self.register_type_name::<C>();
+        let callbacks = &mut self.component_reactors
             .entry(TypeId::of::<C>())
             .or_default()
-            .removal_callbacks
-            .push(sys_handle.clone());
+            .removal_callbacks;
+        callbacks.push(sys_handle.clone());
+        let count = callbacks.len();
+        self.check_reactor_count(ReactTriggerKind::ComponentRemoval, &std::any::type_name::<C>(), count);
 
         ReactorType::ComponentRemoval(TypeId::of::<C>())
     }
@@ -234,20 +309,22 @@ impl ReactCache
         sys_handle: &AutoDespawnSignal,
     ) -> ReactorType
     {
-        self.resource_reactors
-            .entry(TypeId::of::<R>())
-            .or_default()
-            .push(sys_handle.clone());
+        self.register_type_name::<R>();
+        let callbacks = self.resource_reactors.entry(TypeId::of::<R>()).or_default();
+        callbacks.push(sys_handle.clone());
+        let count = callbacks.len();
+        self.check_reactor_count(ReactTriggerKind::ResourceMutation, &std::any::type_name::<R>(), count);
 
         ReactorType::ResourceMutation(TypeId::of::<R>())
     }
 
     pub(crate) fn register_event_reactor<E: 'static>(&mut self, sys_handle: &AutoDespawnSignal) -> ReactorType
     {
-        self.event_reactors
-            .entry(TypeId::of::<E>())
-            .or_default()
-            .push(sys_handle.clone());
+        self.register_type_name::<E>();
+        let callbacks = self.event_reactors.entry(TypeId::of::<E>()).or_default();
+        callbacks.push(sys_handle.clone());
+        let count = callbacks.len();
+        self.check_reactor_count(ReactTriggerKind::Event, &std::any::type_name::<E>(), count);
 
         ReactorType::Event(TypeId::of::<E>())
     }
@@ -255,10 +332,10 @@ impl ReactCache
     pub(crate) fn register_despawn_reactor(&mut self, entity: Entity, callonce: CallOnce<()>) -> RevokeToken
     {
         let despawn_id = self.next_despawn_id();
-        self.despawn_reactors
-            .entry(entity)
-            .or_default()
-            .push((despawn_id, callonce));
+        let callbacks = self.despawn_reactors.entry(entity).or_default();
+        callbacks.push((despawn_id, callonce));
+        let count = callbacks.len();
+        self.check_reactor_count(ReactTriggerKind::Despawn, &entity, count);
 
         RevokeToken{ reactors: vec![ReactorType::Despawn(entity)].into(), id: despawn_id }
     }
@@ -467,17 +544,21 @@ impl Default for ReactCache
         let (despawn_sender, despawn_receiver) = new_channel::<Entity>();
 
         Self{
-            despawn_counter       : 0,
-            entity_reactors_query : None,
-            component_reactors    : HashMap::default(),
-            tracked_removals      : HashSet::default(),
-            removal_checkers      : Vec::new(),
-            removal_buffer        : None,
-            despawn_reactors      : HashMap::new(),
+            despawn_counter           : 0,
+            entity_reactors_query     : None,
+            component_reactors        : HashMap::default(),
+            tracked_removals          : HashSet::default(),
+            removal_checkers          : Vec::new(),
+            removal_buffer            : None,
+            despawn_reactors          : HashMap::new(),
             despawn_sender,
             despawn_receiver,
-            resource_reactors     : HashMap::new(),
-            event_reactors        : HashMap::new(),
+            resource_reactors         : HashMap::new(),
+            event_reactors            : HashMap::new(),
+            history                   : ReactHistory::default(),
+            type_names                : HashMap::new(),
+            reactor_warning_threshold : None,
+            warned_reactor_targets    : HashSet::default(),
         }
     }
 }
@@ -156,6 +156,40 @@ impl<'w, 's> ReactCommands<'w, 's>
         self.cache.react_to_resource_mutation::<R>(&mut self.commands);
     }
 
+    /// Close the current react history group.
+    ///
+    /// Mutations of types tracked with [`ReactHistoryAppExt`] are collected into one group until a checkpoint is made.
+    /// Each call to [`Self::undo()`] or [`Self::redo()`] traverses one group.
+    pub fn checkpoint(&mut self)
+    {
+        self.cache.history_mut().checkpoint();
+    }
+
+    /// Undo the most recent react history group.
+    /// - The history is traversed after `apply_deferred` is invoked. Mutations recorded since the last checkpoint are
+    ///   included in the undone group.
+    /// - Mutation reactions are triggered for every restored value.
+    /// - Mutations made by those reactions are not recorded.
+    pub fn undo(&mut self)
+    {
+        self.commands.add(|world: &mut World| apply_react_history(world, HistoryDirection::Undo));
+    }
+
+    /// Redo the most recently undone react history group.
+    /// - The history is traversed after `apply_deferred` is invoked.
+    /// - Mutation reactions are triggered for every restored value.
+    /// - Recording a new mutation discards all redoable groups.
+    pub fn redo(&mut self)
+    {
+        self.commands.add(|world: &mut World| apply_react_history(world, HistoryDirection::Redo));
+    }
+
+    /// Discard all react history.
+    pub fn clear_history(&mut self)
+    {
+        self.cache.history_mut().clear();
+    }
+
     /// Revoke a reactor.
     /// - Entity reactors: revoked after `apply_deferred` is invoked.
     /// - Component, despawn, resource, event reactors: revoked immediately.
@@ -240,8 +274,12 @@ impl<'w, 's> ReactCommands<'w, 's>
     where
         I: Send + Sync + 'static
     {
+        #[cfg(feature = "testing")]
+        let reactor_id = ReactorId::of_val(&reactor);
         let sys_id = self.commands.spawn_system(reactor);
         let sys_handle = self.despawner.prepare(sys_id.entity());
+        #[cfg(feature = "testing")]
+        self.commands.entity(sys_id.entity()).insert(ReactorInfo(reactor_id));
 
         reactor_registration(self, &sys_handle, triggers)
     }
@@ -260,7 +298,7 @@ impl<'w, 's> ReactCommands<'w, 's>
         &mut self,
         entity  : Entity,
         reactor : impl IntoSystem<(), (), Marker> + Send + Sync + 'static
-    ) -> Result<RevokeToken, ()>
+    ) -> Result<RevokeToken, SysCallError>
     {
         register_despawn_reactor(self, entity, reactor)
     }
@@ -284,6 +322,8 @@ impl<'w, 's> ReactCommands<'w, 's>
     {
         // register reactors
         let entity = self.commands.spawn_empty().id();
+        #[cfg(feature = "testing")]
+        self.commands.entity(entity).insert(ReactorInfo(ReactorId::of_val(&reactor)));
         let sys_handle = self.despawner.prepare(entity);
         let revoke_token = reactor_registration(self, &sys_handle, triggers);
 
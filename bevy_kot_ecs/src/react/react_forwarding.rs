@@ -0,0 +1,136 @@
+//local shortcuts
+use crate::*;
+use bevy_kot_utils::{Receiver, Sender};
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_event_forwarder<E: Clone + Send + Sync + 'static>(In(sender): In<Sender<E>>, mut rcommands: ReactCommands)
+{
+    rcommands.on(event::<E>(),
+            move |mut events: ReactEventReader<E>|
+            {
+                let Some(event) = events.next() else { return; };
+                if sender.send(event.clone()).is_err()
+                { tracing::warn!("failed forwarding react event {}, receiver dropped", std::any::type_name::<E>()); }
+            }
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_resource_forwarder<R: ReactResource + Clone>(In(sender): In<Sender<R>>, mut rcommands: ReactCommands)
+{
+    rcommands.on(resource_mutation::<R>(),
+            move |resource: ReactRes<R>|
+            {
+                if sender.send((*resource).clone()).is_err()
+                { tracing::warn!("failed forwarding react resource {}, receiver dropped", std::any::type_name::<R>()); }
+            }
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set in `First` where forwarded react events and resource mutations are received.
+#[derive(SystemSet, Debug, Default, Hash, Eq, PartialEq, Clone)]
+pub struct ReactForwardSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends the `App` API with methods for forwarding reactions between worlds.
+///
+/// Use [`new_channel()`](bevy_kot_utils::new_channel) to connect the sending app (or `SubApp`) with the receiving
+/// app. Forwarded values are received in [`ReactForwardSet`] and surface as normal reactions in the receiving world.
+///
+/// Example:
+/// ```no_run
+/// let (sender, receiver) = new_channel::<SimTick>();
+/// sim_app.forward_react_event(sender);
+/// ui_app.receive_react_events(receiver);
+/// ```
+pub trait ReactForwardAppExt
+{
+    /// Forward all react events of type `E` sent in this app to `sender`.
+    ///
+    /// Panics if [`ReactPlugin`] was not added to the app.
+    fn forward_react_event<E: Clone + Send + Sync + 'static>(&mut self, sender: Sender<E>) -> &mut Self;
+
+    /// Forward the value of react resource `R` to `sender` every time it is mutated.
+    ///
+    /// If the resource already exists, its current value is forwarded immediately so the receiver starts in sync.
+    ///
+    /// Panics if [`ReactPlugin`] was not added to the app.
+    fn forward_react_resource<R: ReactResource + Clone>(&mut self, sender: Sender<R>) -> &mut Self;
+
+    /// Send react events of type `E` received from `receiver` in this app.
+    ///
+    /// Events are sent with [`ReactCommands::send()`] in the order they were received, so event reactors will run
+    /// once per forwarded event. The react event is added to this app automatically.
+    fn receive_react_events<E: Send + Sync + 'static>(&mut self, receiver: Receiver<E>) -> &mut Self;
+
+    /// Set react resource `R` to values received from `receiver` in this app.
+    ///
+    /// Values are applied as normal resource mutations, so resource mutation reactors will run. If several values were
+    /// received since the last update, only the latest is applied. If the resource doesn't exist it will be inserted.
+    fn receive_react_resource<R: ReactResource>(&mut self, receiver: Receiver<R>) -> &mut Self;
+}
+
+impl ReactForwardAppExt for App
+{
+    fn forward_react_event<E: Clone + Send + Sync + 'static>(&mut self, sender: Sender<E>) -> &mut Self
+    {
+        assert!(self.world.contains_resource::<ReactCache>(), "ReactPlugin is missing for react forwarding");
+        syscall(&mut self.world, sender, register_event_forwarder::<E>);
+        self
+    }
+
+    fn forward_react_resource<R: ReactResource + Clone>(&mut self, sender: Sender<R>) -> &mut Self
+    {
+        assert!(self.world.contains_resource::<ReactCache>(), "ReactPlugin is missing for react forwarding");
+        if let Some(resource) = self.world.get_react_resource::<R>()
+        {
+            let _ = sender.send(resource.clone());
+        }
+        syscall(&mut self.world, sender, register_resource_forwarder::<R>);
+        self
+    }
+
+    fn receive_react_events<E: Send + Sync + 'static>(&mut self, receiver: Receiver<E>) -> &mut Self
+    {
+        self.add_react_event::<E>()
+            .add_systems(First,
+                (
+                    move |mut rcommands: ReactCommands|
+                    {
+                        while let Some(event) = receiver.try_recv() { rcommands.send(event); }
+                    }
+                ).in_set(ReactForwardSet)
+            )
+    }
+
+    fn receive_react_resource<R: ReactResource>(&mut self, receiver: Receiver<R>) -> &mut Self
+    {
+        self.add_systems(First,
+                (
+                    move |world: &mut World|
+                    {
+                        let mut latest = None;
+                        while let Some(value) = receiver.try_recv() { latest = Some(value); }
+                        let Some(value) = latest else { return; };
+                        set_react_resource(world, value);
+                    }
+                ).in_set(ReactForwardSet)
+            )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
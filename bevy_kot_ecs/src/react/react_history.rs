@@ -0,0 +1,389 @@
+//local shortcuts
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+
+//standard shortcuts
+use core::any::{Any, TypeId};
+use std::collections::VecDeque;
+use std::vec::Vec;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+type ResourceSnapshotter = fn(&dyn Any) -> Option<Box<dyn HistoryEntry>>;
+type ComponentSnapshotter = fn(Entity, &dyn Any) -> Option<Box<dyn HistoryEntry>>;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn trigger_component_mutation<C: ReactComponent>(In(entity): In<Entity>, mut rcommands: ReactCommands)
+{
+    rcommands.cache.react_to_mutation::<C>(&mut rcommands.commands, entity);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Identifies the target of a history entry.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum HistoryKey
+{
+    Resource(TypeId),
+    Component(Entity, TypeId),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A recorded value that can be swapped with its target in the world.
+trait HistoryEntry: Send + Sync + 'static
+{
+    fn key(&self) -> HistoryKey;
+
+    /// Swap the recorded value with the target's current value.
+    /// - Returns `false` if the target no longer exists.
+    fn swap(&mut self, world: &mut World) -> bool;
+
+    /// Trigger mutation reactions for the target.
+    fn react(&self, world: &mut World);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct ResourceSnapshot<R: ReactResource + Clone>
+{
+    value: R,
+}
+
+impl<R: ReactResource + Clone> HistoryEntry for ResourceSnapshot<R>
+{
+    fn key(&self) -> HistoryKey
+    {
+        HistoryKey::Resource(TypeId::of::<R>())
+    }
+
+    fn swap(&mut self, world: &mut World) -> bool
+    {
+        let Some(current) = world.get_react_resource_mut_noreact::<R>() else { return false; };
+        std::mem::swap(current, &mut self.value);
+        true
+    }
+
+    fn react(&self, world: &mut World)
+    {
+        world.trigger_resource_mutation::<R>();
+    }
+}
+
+fn snapshot_resource<R: ReactResource + Clone>(value: &dyn Any) -> Option<Box<dyn HistoryEntry>>
+{
+    let value = value.downcast_ref::<R>()?;
+    Some(Box::new(ResourceSnapshot{ value: value.clone() }))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct ComponentSnapshot<C: ReactComponent + Clone>
+{
+    entity : Entity,
+    value  : C,
+}
+
+impl<C: ReactComponent + Clone> HistoryEntry for ComponentSnapshot<C>
+{
+    fn key(&self) -> HistoryKey
+    {
+        HistoryKey::Component(self.entity, TypeId::of::<C>())
+    }
+
+    fn swap(&mut self, world: &mut World) -> bool
+    {
+        let Some(mut current) = world.get_mut::<React<C>>(self.entity) else { return false; };
+        std::mem::swap(&mut current.component, &mut self.value);
+        true
+    }
+
+    fn react(&self, world: &mut World)
+    {
+        syscall(world, self.entity, trigger_component_mutation::<C>);
+    }
+}
+
+fn snapshot_component<C: ReactComponent + Clone>(entity: Entity, value: &dyn Any) -> Option<Box<dyn HistoryEntry>>
+{
+    let value = value.downcast_ref::<C>()?;
+    Some(Box::new(ComponentSnapshot{ entity, value: value.clone() }))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn set_replaying(world: &mut World, replaying: bool)
+{
+    let mut react_cache = world.resource_mut::<ReactCache>();
+    let history = react_cache.history_mut();
+    match replaying
+    {
+        true  => history.replay_depth += 1,
+        false => history.replay_depth = history.replay_depth.saturating_sub(1),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Traverse the react history in the specified direction.
+pub(crate) fn apply_react_history(world: &mut World, direction: HistoryDirection)
+{
+    // take the next history group
+    // - the open group is closed first so it can be traversed
+    let Some(mut react_cache) = world.get_resource_mut::<ReactCache>() else { return; };
+    let history = react_cache.history_mut();
+    history.checkpoint();
+    let Some(mut group) = history.take_group(direction) else { return; };
+
+    // swap recorded values into the world
+    // - groups are reversed on each traversal so undo resolves entries in the opposite order they were recorded
+    // - entries whose targets no longer exist are discarded
+    group.reverse();
+    group.retain_mut(|entry| entry.swap(world));
+
+    // react to the restored values
+    // - history is not recorded while these reactions run, so reactors cannot clobber the history stacks
+    set_replaying(world, true);
+    for entry in group.iter()
+    {
+        entry.react(world);
+    }
+    set_replaying(world, false);
+
+    // save the group so it can be traversed in the opposite direction
+    if group.is_empty() { return; }
+    world.resource_mut::<ReactCache>().history_mut().push_group(direction.opposite(), group);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum HistoryDirection
+{
+    Undo,
+    Redo,
+}
+
+impl HistoryDirection
+{
+    fn opposite(self) -> Self
+    {
+        match self
+        {
+            HistoryDirection::Undo => HistoryDirection::Redo,
+            HistoryDirection::Redo => HistoryDirection::Undo,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records mutations of tracked react resources and react components.
+///
+/// Mutations are collected into an 'open' group until a checkpoint is made. Only the first mutation of each target in
+/// a group is recorded, since undoing the group only needs the value from before the group started.
+///
+/// If a max depth is set, the oldest undo groups are discarded when new groups are closed.
+#[derive(Default)]
+pub(crate) struct ReactHistory
+{
+    /// Snapshot makers for tracked resources.
+    resource_snapshotters: HashMap<TypeId, ResourceSnapshotter>,
+    /// Snapshot makers for tracked components.
+    component_snapshotters: HashMap<TypeId, ComponentSnapshotter>,
+
+    /// Targets recorded in the open group (cached to prevent duplicate entries).
+    open_keys: HashSet<HistoryKey>,
+    /// The group currently being recorded.
+    open_group: Vec<Box<dyn HistoryEntry>>,
+
+    /// Groups that can be undone (most recent last).
+    undo_stack: VecDeque<Vec<Box<dyn HistoryEntry>>>,
+    /// Groups that can be redone (most recent last).
+    redo_stack: Vec<Vec<Box<dyn HistoryEntry>>>,
+
+    /// Number of in-progress history traversals. Mutations are not recorded while traversing.
+    replay_depth: u32,
+    /// Max number of undo groups to keep (unbounded if `None`).
+    max_depth: Option<usize>,
+}
+
+impl ReactHistory
+{
+    pub(crate) fn track_resource<R: ReactResource + Clone>(&mut self)
+    {
+        self.resource_snapshotters.insert(TypeId::of::<R>(), snapshot_resource::<R>);
+    }
+
+    pub(crate) fn track_component<C: ReactComponent + Clone>(&mut self)
+    {
+        self.component_snapshotters.insert(TypeId::of::<C>(), snapshot_component::<C>);
+    }
+
+    /// Record the value of a resource that is about to be mutated.
+    /// - Does nothing if the resource type is not tracked.
+    pub(crate) fn record_resource<R: ReactResource>(&mut self, value: &R)
+    {
+        if self.resource_snapshotters.is_empty() { return; }
+        let Some(snapshotter) = self.resource_snapshotters.get(&TypeId::of::<R>()) else { return; };
+        let key = HistoryKey::Resource(TypeId::of::<R>());
+        if !self.should_record(key) { return; }
+        let Some(entry) = (snapshotter)(value) else { return; };
+        self.record(entry);
+    }
+
+    /// Record the value of a component that is about to be mutated.
+    /// - Does nothing if the component type is not tracked.
+    pub(crate) fn record_component<C: ReactComponent>(&mut self, entity: Entity, value: &C)
+    {
+        if self.component_snapshotters.is_empty() { return; }
+        let Some(snapshotter) = self.component_snapshotters.get(&TypeId::of::<C>()) else { return; };
+        let key = HistoryKey::Component(entity, TypeId::of::<C>());
+        if !self.should_record(key) { return; }
+        let Some(entry) = (snapshotter)(entity, value) else { return; };
+        self.record(entry);
+    }
+
+    /// Set the max number of undo groups to keep.
+    /// - Excess groups are discarded immediately, oldest first.
+    pub(crate) fn set_max_depth(&mut self, max_depth: Option<usize>)
+    {
+        self.max_depth = max_depth;
+        self.enforce_max_depth();
+    }
+
+    /// Close the open group.
+    /// - Does nothing if no mutations were recorded since the last checkpoint.
+    pub(crate) fn checkpoint(&mut self)
+    {
+        if self.open_group.is_empty() { return; }
+        self.open_keys.clear();
+        let group = std::mem::take(&mut self.open_group);
+        self.push_group(HistoryDirection::Undo, group);
+    }
+
+    /// Discard all recorded history.
+    pub(crate) fn clear(&mut self)
+    {
+        self.open_keys.clear();
+        self.open_group.clear();
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+
+    fn should_record(&self, key: HistoryKey) -> bool
+    {
+        self.replay_depth == 0 && !self.open_keys.contains(&key)
+    }
+
+    fn record(&mut self, entry: Box<dyn HistoryEntry>)
+    {
+        // new mutations invalidate the redo stack
+        self.redo_stack.clear();
+        self.open_keys.insert(entry.key());
+        self.open_group.push(entry);
+    }
+
+    fn enforce_max_depth(&mut self)
+    {
+        let Some(max_depth) = self.max_depth else { return; };
+        let excess = self.undo_stack.len().saturating_sub(max_depth);
+        self.undo_stack.drain(..excess);
+    }
+
+    fn take_group(&mut self, direction: HistoryDirection) -> Option<Vec<Box<dyn HistoryEntry>>>
+    {
+        match direction
+        {
+            HistoryDirection::Undo => self.undo_stack.pop_back(),
+            HistoryDirection::Redo => self.redo_stack.pop(),
+        }
+    }
+
+    fn push_group(&mut self, direction: HistoryDirection, group: Vec<Box<dyn HistoryEntry>>)
+    {
+        match direction
+        {
+            HistoryDirection::Undo =>
+            {
+                self.undo_stack.push_back(group);
+                self.enforce_max_depth();
+            }
+            HistoryDirection::Redo => self.redo_stack.push(group),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends the `App` API with react history methods.
+///
+/// Tracked types record their previous value whenever they are mutated with `get_mut(&mut rcommands)`. Use
+/// [`ReactCommands::checkpoint()`], [`ReactCommands::undo()`], and [`ReactCommands::redo()`] to traverse the history.
+///
+/// Mutations made with `get_mut_noreact()` are not recorded.
+pub trait ReactHistoryAppExt
+{
+    /// Record mutations of the react resource `R`.
+    ///
+    /// Panics if [`ReactPlugin`] was not added to the app.
+    fn track_resource_history<R: ReactResource + Clone>(&mut self) -> &mut Self;
+
+    /// Record mutations of the react component `C` on all entities.
+    ///
+    /// Panics if [`ReactPlugin`] was not added to the app.
+    fn track_component_history<C: ReactComponent + Clone>(&mut self) -> &mut Self;
+
+    /// Set the max number of checkpoint groups that can be undone. Pass `None` for unbounded history (the default).
+    /// - When a new group is closed and the history is full, the oldest group is discarded.
+    ///
+    /// Panics if [`ReactPlugin`] was not added to the app.
+    fn set_react_history_depth(&mut self, max_depth: Option<usize>) -> &mut Self;
+}
+
+impl ReactHistoryAppExt for App
+{
+    fn track_resource_history<R: ReactResource + Clone>(&mut self) -> &mut Self
+    {
+        self.world
+            .get_resource_mut::<ReactCache>()
+            .expect("ReactPlugin is missing for react history")
+            .history_mut()
+            .track_resource::<R>();
+        self
+    }
+
+    fn track_component_history<C: ReactComponent + Clone>(&mut self) -> &mut Self
+    {
+        self.world
+            .get_resource_mut::<ReactCache>()
+            .expect("ReactPlugin is missing for react history")
+            .history_mut()
+            .track_component::<C>();
+        self
+    }
+
+    fn set_react_history_depth(&mut self, max_depth: Option<usize>) -> &mut Self
+    {
+        self.world
+            .get_resource_mut::<ReactCache>()
+            .expect("ReactPlugin is missing for react history")
+            .history_mut()
+            .set_max_depth(max_depth);
+        self
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,360 @@
+//local shortcuts
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+//standard shortcuts
+use core::any::TypeId;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::vec::Vec;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+type SaveFn = fn(&World, &Path, PersistFormat) -> Result<(), PersistError>;
+type LoadFn = fn(&mut World, &Path, PersistFormat) -> Result<(), PersistError>;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Registration info for a persistent react resource.
+#[derive(Clone)]
+struct PersistEntry
+{
+    type_id   : TypeId,
+    type_name : &'static str,
+    path      : PathBuf,
+    format    : PersistFormat,
+    save      : SaveFn,
+    load      : LoadFn,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks persistent react resources.
+/// - Entries are stored in registration order so bulk loads react in a predictable order.
+#[derive(Resource, Default)]
+struct ReactPersistence
+{
+    entries: Vec<PersistEntry>,
+}
+
+impl ReactPersistence
+{
+    fn get<R: ReactResource>(&self) -> Result<PersistEntry, PersistError>
+    {
+        self.entries
+            .iter()
+            .find(|entry| entry.type_id == TypeId::of::<R>())
+            .cloned()
+            .ok_or(PersistError::Unregistered(std::any::type_name::<R>()))
+    }
+
+    fn insert(&mut self, entry: PersistEntry)
+    {
+        match self.entries.iter_mut().find(|e| e.type_id == entry.type_id)
+        {
+            Some(existing) => *existing = entry,
+            None           => self.entries.push(entry),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn serialize<R: Serialize>(value: &R, format: PersistFormat) -> Result<String, PersistError>
+{
+    match format
+    {
+        PersistFormat::Json =>
+        {
+            serde_json::to_string_pretty(value).map_err(|err| PersistError::Serialize(err.to_string()))
+        }
+        PersistFormat::Ron =>
+        {
+            ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+                .map_err(|err| PersistError::Serialize(err.to_string()))
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn deserialize<R: DeserializeOwned>(serialized: &str, format: PersistFormat) -> Result<R, PersistError>
+{
+    match format
+    {
+        PersistFormat::Json => serde_json::from_str(serialized).map_err(|err| PersistError::Deserialize(err.to_string())),
+        PersistFormat::Ron  => ron::from_str(serialized).map_err(|err| PersistError::Deserialize(err.to_string())),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn save_impl<R: ReactResource + Serialize>(world: &World, path: &Path, format: PersistFormat) -> Result<(), PersistError>
+{
+    let value = world
+        .get_react_resource::<R>()
+        .ok_or(PersistError::MissingResource(std::any::type_name::<R>()))?;
+    let serialized = serialize(value, format)?;
+
+    if let Some(parent) = path.parent() { std::fs::create_dir_all(parent).map_err(PersistError::Io)?; }
+    std::fs::write(path, serialized).map_err(PersistError::Io)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn load_impl<R: ReactResource + DeserializeOwned>(
+    world  : &mut World,
+    path   : &Path,
+    format : PersistFormat
+) -> Result<(), PersistError>
+{
+    let serialized = std::fs::read_to_string(path).map_err(PersistError::Io)?;
+    let value = deserialize::<R>(&serialized, format)?;
+
+    // mutate the resource so reactions (and history) behave exactly as runtime mutations
+    set_react_resource(world, value);
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// File format for persistent react resources.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PersistFormat
+{
+    Json,
+    Ron,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Error that can occur when saving or loading react resources.
+#[derive(Debug)]
+pub enum PersistError
+{
+    /// The resource type was not registered with [`ReactPersistAppExt::persist_react_resource()`].
+    Unregistered(&'static str),
+    /// The resource does not exist in the world.
+    MissingResource(&'static str),
+    /// Failed to read or write the resource's file.
+    Io(std::io::Error),
+    /// Failed to serialize the resource.
+    Serialize(String),
+    /// Failed to deserialize the resource.
+    Deserialize(String),
+}
+
+impl PersistError
+{
+    /// Returns `true` if the error was caused by a missing file.
+    pub fn is_not_found(&self) -> bool
+    {
+        matches!(self, PersistError::Io(err) if err.kind() == std::io::ErrorKind::NotFound)
+    }
+}
+
+impl Display for PersistError
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            PersistError::Unregistered(name)    => write!(f, "react resource {name} is not registered for persistence"),
+            PersistError::MissingResource(name) => write!(f, "react resource {name} is missing"),
+            PersistError::Io(err)               => write!(f, "persistence io error: {err}"),
+            PersistError::Serialize(err)        => write!(f, "failed serializing react resource: {err}"),
+            PersistError::Deserialize(err)      => write!(f, "failed deserializing react resource: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PersistError
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)>
+    {
+        match self
+        {
+            PersistError::Io(err) => Some(err),
+            _                     => None,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends the `App` API with react resource persistence.
+pub trait ReactPersistAppExt
+{
+    /// Register a react resource to be saved to and loaded from a file.
+    ///
+    /// Re-registering a resource replaces its file path and format. The resource is not loaded automatically, use
+    /// [`ReactPersistWorldExt`] or [`ReactPersistCommandsExt`] to save and load.
+    fn persist_react_resource<R: ReactResource + Serialize + DeserializeOwned>(
+        &mut self,
+        path   : impl Into<PathBuf>,
+        format : PersistFormat,
+    ) -> &mut Self;
+}
+
+impl ReactPersistAppExt for App
+{
+    fn persist_react_resource<R: ReactResource + Serialize + DeserializeOwned>(
+        &mut self,
+        path   : impl Into<PathBuf>,
+        format : PersistFormat,
+    ) -> &mut Self
+    {
+        self.world
+            .get_resource_or_insert_with(ReactPersistence::default)
+            .insert(PersistEntry{
+                type_id   : TypeId::of::<R>(),
+                type_name : std::any::type_name::<R>(),
+                path      : path.into(),
+                format,
+                save      : save_impl::<R>,
+                load      : load_impl::<R>,
+            });
+        self
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends the `World` API with react resource persistence.
+///
+/// Loading a resource mutates it with [`ReactResMut::get_mut()`], so resource mutation reactions run exactly as if
+/// the value was set at runtime. If the resource doesn't exist it will be inserted, then mutation reactions will be
+/// triggered.
+pub trait ReactPersistWorldExt
+{
+    /// Save a registered react resource to its file.
+    fn save_react_resource<R: ReactResource>(&self) -> Result<(), PersistError>;
+    /// Load a registered react resource from its file.
+    fn load_react_resource<R: ReactResource>(&mut self) -> Result<(), PersistError>;
+    /// Save all registered react resources.
+    /// - All resources are saved even if some fail. Returns the first error encountered.
+    fn save_react_resources(&self) -> Result<(), PersistError>;
+    /// Load all registered react resources in the order they were registered.
+    /// - Resources whose files don't exist are skipped.
+    /// - All resources are loaded even if some fail. Returns the first error encountered.
+    fn load_react_resources(&mut self) -> Result<(), PersistError>;
+}
+
+impl ReactPersistWorldExt for World
+{
+    fn save_react_resource<R: ReactResource>(&self) -> Result<(), PersistError>
+    {
+        let entry = self
+            .get_resource::<ReactPersistence>()
+            .ok_or(PersistError::Unregistered(std::any::type_name::<R>()))?
+            .get::<R>()?;
+        (entry.save)(self, &entry.path, entry.format)
+    }
+
+    fn load_react_resource<R: ReactResource>(&mut self) -> Result<(), PersistError>
+    {
+        let entry = self
+            .get_resource::<ReactPersistence>()
+            .ok_or(PersistError::Unregistered(std::any::type_name::<R>()))?
+            .get::<R>()?;
+        (entry.load)(self, &entry.path, entry.format)
+    }
+
+    fn save_react_resources(&self) -> Result<(), PersistError>
+    {
+        let Some(persistence) = self.get_resource::<ReactPersistence>() else { return Ok(()); };
+        let mut result = Ok(());
+
+        for entry in persistence.entries.iter()
+        {
+            let Err(err) = (entry.save)(self, &entry.path, entry.format) else { continue; };
+            tracing::warn!(entry.type_name, ?err, "failed saving react resource");
+            if result.is_ok() { result = Err(err); }
+        }
+
+        result
+    }
+
+    fn load_react_resources(&mut self) -> Result<(), PersistError>
+    {
+        let Some(persistence) = self.get_resource::<ReactPersistence>() else { return Ok(()); };
+        let entries = persistence.entries.clone();
+        let mut result = Ok(());
+
+        for entry in entries.iter()
+        {
+            let Err(err) = (entry.load)(self, &entry.path, entry.format) else { continue; };
+            if err.is_not_found() { continue; }
+            tracing::warn!(entry.type_name, ?err, "failed loading react resource");
+            if result.is_ok() { result = Err(err); }
+        }
+
+        result
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends the `Commands` API with react resource persistence.
+///
+/// Errors are logged. See [`ReactPersistWorldExt`].
+pub trait ReactPersistCommandsExt
+{
+    /// Schedule a registered react resource to be saved to its file.
+    fn save_react_resource<R: ReactResource>(&mut self);
+    /// Schedule a registered react resource to be loaded from its file.
+    fn load_react_resource<R: ReactResource>(&mut self);
+    /// Schedule all registered react resources to be saved.
+    fn save_react_resources(&mut self);
+    /// Schedule all registered react resources to be loaded.
+    fn load_react_resources(&mut self);
+}
+
+impl<'w, 's> ReactPersistCommandsExt for Commands<'w, 's>
+{
+    fn save_react_resource<R: ReactResource>(&mut self)
+    {
+        self.add(
+                |world: &mut World|
+                {
+                    let Err(err) = world.save_react_resource::<R>() else { return; };
+                    tracing::warn!(?err, "failed saving react resource {}", std::any::type_name::<R>());
+                }
+            );
+    }
+
+    fn load_react_resource<R: ReactResource>(&mut self)
+    {
+        self.add(
+                |world: &mut World|
+                {
+                    let Err(err) = world.load_react_resource::<R>() else { return; };
+                    tracing::warn!(?err, "failed loading react resource {}", std::any::type_name::<R>());
+                }
+            );
+    }
+
+    fn save_react_resources(&mut self)
+    {
+        self.add(|world: &mut World| { let _ = world.save_react_resources(); });
+    }
+
+    fn load_react_resources(&mut self)
+    {
+        self.add(|world: &mut World| { let _ = world.load_react_resources(); });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -20,6 +20,31 @@ fn trigger_resource_mutation<R: ReactResource>(mut rcommands: ReactCommands)
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+fn set_react_resource_value<R: ReactResource>(In(value): In<R>, mut rcommands: ReactCommands, mut react_res: ReactResMut<R>)
+{
+    *react_res.get_mut(&mut rcommands) = value;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Set the value of a react resource and trigger reactions (and record history) as if it was mutated at runtime.
+/// - If the resource doesn't exist it will be inserted, then mutation reactions will be triggered.
+pub(crate) fn set_react_resource<R: ReactResource>(world: &mut World, value: R)
+{
+    if !world.contains_react_resource::<R>()
+    {
+        world.insert_react_resource(value);
+        world.trigger_resource_mutation::<R>();
+        return;
+    }
+
+    syscall(world, value, set_react_resource_value::<R>);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Resource wrapper that enables reacting to resource mutations.
 #[derive(Resource)]
 struct ReactResInner<R: ReactResource>
@@ -38,6 +63,7 @@ impl<R: ReactResource> ReactResInner<R>
     /// Mutably access the resource and trigger reactions.
     pub fn get_mut<'a>(&'a mut self, rcommands: &mut ReactCommands) -> &'a mut R
     {
+        rcommands.cache.history_mut().record_resource(&self.resource);
         rcommands.trigger_resource_mutation::<R>();
         &mut self.resource
     }
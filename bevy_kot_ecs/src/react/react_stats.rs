@@ -0,0 +1,151 @@
+//local shortcuts
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The kind of trigger a reactor is registered to.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ReactTriggerKind
+{
+    EntityInsertion,
+    EntityMutation,
+    EntityRemoval,
+    ComponentInsertion,
+    ComponentMutation,
+    ComponentRemoval,
+    ResourceMutation,
+    Event,
+    Despawn,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Counts of live reactors.
+///
+/// Reactors registered to multiple triggers are counted once per trigger.
+#[derive(Default, Clone, Debug)]
+pub struct ReactStats
+{
+    /// Total number of reactor registrations.
+    pub total: usize,
+    /// Reactor registrations per trigger kind.
+    pub per_trigger: HashMap<ReactTriggerKind, usize>,
+    /// Reactor registrations per component/resource/event type name (all trigger kinds combined).
+    pub per_type: HashMap<&'static str, usize>,
+    /// Reactor registrations per entity, for entity-specific triggers and despawn reactors.
+    pub per_entity: HashMap<Entity, usize>,
+}
+
+impl ReactStats
+{
+    /// Get the number of reactors registered to a trigger kind.
+    pub fn trigger_count(&self, kind: ReactTriggerKind) -> usize
+    {
+        self.per_trigger.get(&kind).copied().unwrap_or_default()
+    }
+
+    /// Get the number of reactors registered to triggers of component/resource/event type `T`.
+    pub fn type_count<T: 'static>(&self) -> usize
+    {
+        self.per_type.get(std::any::type_name::<T>()).copied().unwrap_or_default()
+    }
+
+    /// Get the number of reactors registered to triggers that target an entity.
+    pub fn entity_count(&self, entity: Entity) -> usize
+    {
+        self.per_entity.get(&entity).copied().unwrap_or_default()
+    }
+
+    pub(crate) fn add(
+        &mut self,
+        kind      : ReactTriggerKind,
+        type_name : Option<&'static str>,
+        entity    : Option<Entity>,
+        count     : usize
+    ){
+        if count == 0 { return; }
+        self.total += count;
+        *self.per_trigger.entry(kind).or_default() += count;
+        if let Some(type_name) = type_name { *self.per_type.entry(type_name).or_default() += count; }
+        if let Some(entity) = entity { *self.per_entity.entry(entity).or_default() += count; }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends the `World` API with react statistics.
+pub trait ReactStatsWorldExt
+{
+    /// Count all live reactors.
+    ///
+    /// Returns empty stats if [`ReactPlugin`] was not added to the app.
+    fn react_stats(&mut self) -> ReactStats;
+}
+
+impl ReactStatsWorldExt for World
+{
+    fn react_stats(&mut self) -> ReactStats
+    {
+        let mut stats = ReactStats::default();
+        let Some(cache) = self.get_resource::<ReactCache>() else { return stats; };
+        cache.collect_stats(&mut stats);
+
+        // collect entity reactors
+        let mut entity_reactors = self.query::<(Entity, &EntityReactors)>();
+        let cache = self.resource::<ReactCache>();
+
+        for (entity, reactors) in entity_reactors.iter(self)
+        {
+            for (kind, callbacks) in [
+                    (ReactTriggerKind::EntityInsertion, &reactors.insertion_callbacks),
+                    (ReactTriggerKind::EntityMutation, &reactors.mutation_callbacks),
+                    (ReactTriggerKind::EntityRemoval, &reactors.removal_callbacks),
+                ]
+            {
+                for (type_id, callbacks) in callbacks.iter()
+                {
+                    stats.add(kind, Some(cache.type_name(*type_id)), Some(entity), callbacks.len());
+                }
+            }
+        }
+
+        stats
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends the `App` API with react statistics.
+pub trait ReactStatsAppExt
+{
+    /// Log a warning when the number of reactors registered to a single trigger target exceeds `threshold`.
+    ///
+    /// For example, with a threshold of 100 a warning will be logged when the 101st reactor is registered for
+    /// `resource_mutation::<MyRes>()`. Useful for catching leaked reactors that were never revoked.
+    /// Each trigger target only logs one warning.
+    ///
+    /// Panics if [`ReactPlugin`] was not added to the app.
+    fn set_reactor_warning_threshold(&mut self, threshold: usize) -> &mut Self;
+}
+
+impl ReactStatsAppExt for App
+{
+    fn set_reactor_warning_threshold(&mut self, threshold: usize) -> &mut Self
+    {
+        self.world
+            .get_resource_mut::<ReactCache>()
+            .expect("ReactPlugin is missing for react stats")
+            .set_reactor_warning_threshold(Some(threshold));
+        self
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
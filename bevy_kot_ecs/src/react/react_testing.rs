@@ -0,0 +1,202 @@
+//local shortcuts
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use core::any::TypeId;
+use std::fmt::{Debug, Formatter};
+use std::ops::{Deref, DerefMut};
+use std::vec::Vec;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Identifies the reactor that owns a reactor system entity.
+#[derive(Component, Copy, Clone)]
+pub(crate) struct ReactorInfo(pub(crate) ReactorId);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records reactions in the order they run.
+/// - Reactions are only recorded if this resource exists (see [`ReactTestApp`]).
+#[derive(Resource, Default)]
+struct ReactionRecorder
+{
+    reactions: Vec<ReactorId>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Record a reaction of the reactor attached to a reactor system.
+pub(crate) fn record_reaction(world: &mut World, sys_id: AnySysId)
+{
+    if !world.contains_resource::<ReactionRecorder>() { return; }
+    let Some(info) = world.get::<ReactorInfo>(sys_id.entity()).copied() else { return; };
+    record_reactor(world, info.0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Record a reaction of a reactor.
+pub(crate) fn record_reactor(world: &mut World, reactor: ReactorId)
+{
+    let Some(mut recorder) = world.get_resource_mut::<ReactionRecorder>() else { return; };
+    recorder.reactions.push(reactor);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Identifies a reactor by the type passed to [`ReactCommands::on()`], [`ReactCommands::once()`], or
+/// [`ReactCommands::on_despawn()`].
+///
+/// Function reactors can't be named as types, so use [`ReactorId::of_val()`] to get their ids.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ReactorId
+{
+    type_id : TypeId,
+    name    : &'static str,
+}
+
+impl ReactorId
+{
+    /// Get the id of reactor type `R`.
+    pub fn of<R: 'static>() -> Self
+    {
+        Self{ type_id: TypeId::of::<R>(), name: std::any::type_name::<R>() }
+    }
+
+    /// Get the id of a reactor.
+    ///
+    /// Example:
+    /// ```no_run
+    /// let id = ReactorId::of_val(&my_reactor_system);
+    /// ```
+    pub fn of_val<R: 'static>(_reactor: &R) -> Self
+    {
+        Self::of::<R>()
+    }
+
+    /// Get the reactor's type name.
+    pub fn name(&self) -> &'static str
+    {
+        self.name
+    }
+}
+
+impl Debug for ReactorId
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str(self.name)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Headless app for testing reactive flows.
+///
+/// Adds [`ReactPlugin`] and records every reaction in the order they run. Dereferences to the inner [`App`].
+///
+/// Example:
+/// ```no_run
+/// let mut app = ReactTestApp::new();
+/// app.syscall((), register_my_reactors);
+/// app.syscall((), mutate_my_resource);
+/// app.assert_reacted_by(&my_reactor, 1);
+/// app.assert_reaction_order([ReactorId::of_val(&my_reactor), ReactorId::of_val(&my_other_reactor)]);
+/// ```
+pub struct ReactTestApp
+{
+    app: App,
+}
+
+impl ReactTestApp
+{
+    /// Make a new test app.
+    pub fn new() -> Self
+    {
+        let mut app = App::new();
+        app.add_plugins(ReactPlugin)
+            .init_resource::<ReactionRecorder>();
+
+        Self{ app }
+    }
+
+    /// Run a system on the app's world.
+    pub fn syscall<I, O, S, Marker>(&mut self, input: I, system: S) -> O
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+        S: IntoSystem<I, O, Marker> + Send + Sync + 'static,
+    {
+        syscall(&mut self.app.world, input, system)
+    }
+
+    /// Get all recorded reactions in the order they ran.
+    pub fn reactions(&self) -> &[ReactorId]
+    {
+        &self.app.world.resource::<ReactionRecorder>().reactions
+    }
+
+    /// Count the recorded reactions of reactor type `R`.
+    pub fn reaction_count<R: 'static>(&self) -> usize
+    {
+        let id = ReactorId::of::<R>();
+        self.reactions().iter().filter(|reaction| **reaction == id).count()
+    }
+
+    /// Discard all recorded reactions.
+    pub fn clear_reactions(&mut self)
+    {
+        self.app.world.resource_mut::<ReactionRecorder>().reactions.clear();
+    }
+
+    /// Panics if reactor type `R` did not react exactly `times` times.
+    #[track_caller]
+    pub fn assert_reacted<R: 'static>(&self, times: usize)
+    {
+        let count = self.reaction_count::<R>();
+        assert_eq!(count, times, "reactor {} reacted {} times, expected {}; reactions: {:?}",
+            std::any::type_name::<R>(), count, times, self.reactions());
+    }
+
+    /// Panics if `reactor` did not react exactly `times` times.
+    ///
+    /// Equivalent to [`Self::assert_reacted()`] for reactors whose types can't be named.
+    #[track_caller]
+    pub fn assert_reacted_by<R: 'static>(&self, _reactor: &R, times: usize)
+    {
+        self.assert_reacted::<R>(times);
+    }
+
+    /// Panics if the recorded reactions don't exactly match `order`.
+    #[track_caller]
+    pub fn assert_reaction_order(&self, order: impl IntoIterator<Item = ReactorId>)
+    {
+        let expected: Vec<ReactorId> = order.into_iter().collect();
+        assert_eq!(self.reactions(), expected.as_slice(), "unexpected reaction order");
+    }
+}
+
+impl Default for ReactTestApp
+{
+    fn default() -> Self { Self::new() }
+}
+
+impl Deref for ReactTestApp
+{
+    type Target = App;
+    fn deref(&self) -> &App { &self.app }
+}
+
+impl DerefMut for ReactTestApp
+{
+    fn deref_mut(&mut self) -> &mut App { &mut self.app }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
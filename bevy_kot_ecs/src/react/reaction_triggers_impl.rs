@@ -65,23 +65,40 @@ fn register_entity_reactor<C: ReactComponent>(
         sys_handle
     ))                  : In<(EntityReactType, Entity, AutoDespawnSignal)>,
     mut commands        : Commands,
+    mut cache           : ResMut<ReactCache>,
     mut entity_reactors : Query<&mut EntityReactors>,
 ){
+    cache.register_type_name::<C>();
+
     // callback adder
+    // - returns the trigger kind and number of reactors for the trigger
     let add_callback_fn =
-        move |entity_reactors: &mut EntityReactors|
+        move |entity_reactors: &mut EntityReactors| -> (ReactTriggerKind, usize)
         {
-            let callbacks = match rtype
+            let (kind, callbacks) = match rtype
             {
-                EntityReactType::Insertion => entity_reactors.insertion_callbacks.entry(TypeId::of::<C>()).or_default(),
-                EntityReactType::Mutation  => entity_reactors.mutation_callbacks.entry(TypeId::of::<C>()).or_default(),
-                EntityReactType::Removal   => entity_reactors.removal_callbacks.entry(TypeId::of::<C>()).or_default(),
+                EntityReactType::Insertion =>
+                (
+                    ReactTriggerKind::EntityInsertion,
+                    entity_reactors.insertion_callbacks.entry(TypeId::of::<C>()).or_default()
+                ),
+                EntityReactType::Mutation =>
+                (
+                    ReactTriggerKind::EntityMutation,
+                    entity_reactors.mutation_callbacks.entry(TypeId::of::<C>()).or_default()
+                ),
+                EntityReactType::Removal =>
+                (
+                    ReactTriggerKind::EntityRemoval,
+                    entity_reactors.removal_callbacks.entry(TypeId::of::<C>()).or_default()
+                ),
             };
             callbacks.push(sys_handle);
+            (kind, callbacks.len())
         };
 
     // add callback to entity
-    match entity_reactors.get_mut(entity)
+    let (kind, count) = match entity_reactors.get_mut(entity)
     {
         Ok(mut entity_reactors) => add_callback_fn(&mut entity_reactors),
         _ =>
@@ -92,10 +109,13 @@ fn register_entity_reactor<C: ReactComponent>(
             let mut entity_reactors = EntityReactors::default();
 
             // add callback and insert to entity
-            add_callback_fn(&mut entity_reactors);
+            let result = add_callback_fn(&mut entity_reactors);
             entity_commands.insert(entity_reactors);
+            result
         }
-    }
+    };
+
+    cache.check_reactor_count(kind, &(entity, std::any::type_name::<C>()), count);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -286,21 +306,25 @@ pub(crate) fn register_despawn_reactor<Marker>(
     rcommands : &mut ReactCommands,
     entity    : Entity,
     reactor   : impl IntoSystem<(), (), Marker> + Send + Sync + 'static
-) -> Result<RevokeToken, ()>
+) -> Result<RevokeToken, SysCallError>
 {
     // if the entity doesn't exist, return a dummy revoke token
-    let Some(_) = rcommands.commands.get_entity(entity) else { return Err(()); };
+    let Some(_) = rcommands.commands.get_entity(entity) else { return Err(SysCallError::MissingEntity(entity)); };
 
     // add despawn tracker
     let notifier = rcommands.cache.despawn_sender();
     rcommands.commands.add(move |world: &mut World| syscall(world, (entity, notifier), add_despawn_tracker));
 
     // register despawn reactor
+    #[cfg(feature = "testing")]
+    let reactor_id = ReactorId::of_val(&reactor);
     let token = rcommands.cache.register_despawn_reactor(
             entity,
             CallOnce::new(
                 move |world|
                 {
+                    #[cfg(feature = "testing")]
+                    record_reactor(world, reactor_id);
                     let mut system = IntoSystem::into_system(reactor);
                     system.initialize(world);
                     system.run((), world);
@@ -32,13 +32,15 @@ pub(crate) fn enque_command(commands: &mut Commands, cb: impl Command)
 /// Queue a named system then react to all removals and despawns.
 /// - Note that all side effects and chained reactions will be applied when the syscall applies its deferred commands.
 ///   This means this reaction's effects will be propagated before any 'sibling' reactions/commands.
-pub(crate) fn enque_reaction<I: Send + Sync + 'static>(commands: &mut Commands, sys_id: SysId, input: I)
+pub(crate) fn enque_reaction<I: Send + Sync + 'static>(commands: &mut Commands, sys_id: SysId<I>, input: I)
 {
     commands.add(
             move |world: &mut World|
             {
-                let Ok(()) = spawned_syscall::<I, ()>(world, sys_id, input)
-                else { tracing::warn!(?sys_id, "reaction system failed"); return; };
+                #[cfg(feature = "testing")]
+                record_reaction(world, sys_id.erase());
+                if let Err(err) = spawned_syscall(world, sys_id, input)
+                { tracing::warn!(?sys_id, ?err, "reaction system failed"); return; };
                 react_to_all_removals_and_despawns(world);
             }
         );
@@ -0,0 +1,249 @@
+//local shortcuts
+use crate::*;
+
+//third-party shortcuts
+use bevy::ecs::archetype::ArchetypeComponentId;
+use bevy::ecs::component::{ComponentId, Tick};
+use bevy::ecs::query::Access;
+use bevy::ecs::schedule::{ExecutorKind, InternedSystemSet};
+use bevy::ecs::system::BoxedSystem;
+use bevy::ecs::world::unsafe_world_cell::UnsafeWorldCell;
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A spawned system returned from a batch.
+struct BatchReturn<I, O>
+{
+    index  : usize,
+    system : BoxedSystem<I, O>,
+    output : Option<O>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adapts a spawned system and its input so it can be added to a schedule.
+///
+/// Schedules don't give back their systems, so the inner system and its output are returned when the schedule is
+/// dropped.
+struct BatchedSystem<I, O>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    index      : usize,
+    system     : Option<BoxedSystem<I, O>>,
+    needs_init : bool,
+    input      : Option<I>,
+    output     : Option<O>,
+    returned   : Arc<Mutex<Vec<BatchReturn<I, O>>>>,
+}
+
+impl<I, O> BatchedSystem<I, O>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    fn inner(&self) -> &BoxedSystem<I, O>
+    {
+        self.system.as_ref().expect("batched system is missing")
+    }
+
+    fn inner_mut(&mut self) -> &mut BoxedSystem<I, O>
+    {
+        self.system.as_mut().expect("batched system is missing")
+    }
+}
+
+impl<I, O> System for BatchedSystem<I, O>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    type In = ();
+    type Out = ();
+
+    fn name(&self) -> Cow<'static, str> { self.inner().name() }
+    fn type_id(&self) -> TypeId { TypeId::of::<Self>() }
+    fn component_access(&self) -> &Access<ComponentId> { self.inner().component_access() }
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> { self.inner().archetype_component_access() }
+    fn is_send(&self) -> bool { self.inner().is_send() }
+    fn is_exclusive(&self) -> bool { self.inner().is_exclusive() }
+
+    unsafe fn run_unsafe(&mut self, _input: (), world: UnsafeWorldCell)
+    {
+        let Some(input) = self.input.take() else { return; };
+        // SAFETY: the inner system has the same access as this system.
+        self.output = Some(self.inner_mut().run_unsafe(input, world));
+    }
+
+    fn run(&mut self, _input: (), world: &mut World)
+    {
+        let Some(input) = self.input.take() else { return; };
+        self.output = Some(self.inner_mut().run(input, world));
+    }
+
+    fn apply_deferred(&mut self, world: &mut World) { self.inner_mut().apply_deferred(world); }
+
+    fn initialize(&mut self, world: &mut World)
+    {
+        // spawned systems keep their state between calls, so only new systems are initialized
+        if !self.needs_init { return; }
+        self.needs_init = false;
+        self.inner_mut().initialize(world);
+    }
+
+    fn update_archetype_component_access(&mut self, world: UnsafeWorldCell)
+    {
+        self.inner_mut().update_archetype_component_access(world);
+    }
+
+    fn check_change_tick(&mut self, change_tick: Tick) { self.inner_mut().check_change_tick(change_tick); }
+    fn default_system_sets(&self) -> Vec<InternedSystemSet> { self.inner().default_system_sets() }
+    fn get_last_run(&self) -> Tick { self.inner().get_last_run() }
+    fn set_last_run(&mut self, last_run: Tick) { self.inner_mut().set_last_run(last_run); }
+}
+
+impl<I, O> Drop for BatchedSystem<I, O>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    fn drop(&mut self)
+    {
+        let Some(system) = self.system.take() else { return; };
+        let Ok(mut returned) = self.returned.lock() else { return; };
+        returned.push(BatchReturn{ index: self.index, system, output: self.output.take() });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Take the system out of a spawned system entity.
+///
+/// Returns the system and whether it needs to be initialized.
+fn take_spawned_system<I, O>(world: &mut World, sys_id: SysId<I, O>) -> Result<(BoxedSystem<I, O>, bool), SysCallError>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    let entity = sys_id.entity();
+    let Some(mut entity_mut) = world.get_entity_mut(entity) else { return Err(SysCallError::MissingEntity(entity)); };
+    let Some(mut spawned_system) = entity_mut.get_mut::<SpawnedSystem<I, O>>()
+    else { return Err(SysCallError::MissingSystem(entity)); };
+    let Some(callback) = spawned_system.system.take() else { return Err(SysCallError::Recursive); };
+
+    match callback
+    {
+        CallbackSystem::Empty               => Err(SysCallError::EmptySystem),
+        CallbackSystem::New(system)         => Ok((system, true)),
+        CallbackSystem::Initialized(system) => Ok((system, false)),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Execute a batch of spawned systems, running systems with non-conflicting data access in parallel.
+///
+/// The systems are added to a temporary multi-threaded schedule. Systems whose access conflicts (e.g. systems that
+/// mutate the same component) and exclusive systems run one at a time in an unspecified order. Once all systems have
+/// run, deferred commands are applied in batch order.
+///
+/// Returns the output of each call in batch order. A call fails with the same errors as [`spawned_syscall()`]. If a
+/// system appears in the batch multiple times, only its first call runs and the others return
+/// [`SysCallError::Recursive`].
+///
+/// Since systems in the batch can't observe each other's deferred commands, this is best suited to large numbers of
+/// independent read-only systems (e.g. reactors or UI callbacks that only read world state and queue commands).
+///
+/// # Example
+///
+/// ```
+/// use bevy_kot_ecs::*;
+/// use bevy::prelude::*;
+///
+/// let mut world = World::new();
+/// let sys_ids: Vec<SysId<u32, u32>> = (0..10)
+///     .map(|_| spawn_system(&mut world, |In(x): In<u32>| x * 2))
+///     .collect();
+///
+/// let results = spawned_syscall_batch(&mut world, sys_ids.iter().copied().zip(0..10));
+/// assert_eq!(results[3], Ok(6));
+/// ```
+pub fn spawned_syscall_batch<I, O>(
+    world : &mut World,
+    batch : impl IntoIterator<Item = (SysId<I, O>, I)>
+) -> Vec<Result<O, SysCallError>>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    // collect the systems
+    let returned = Arc::new(Mutex::new(Vec::new()));
+    let mut schedule = Schedule::default();
+    schedule
+        .set_executor_kind(ExecutorKind::MultiThreaded)
+        .set_apply_final_deferred(false);
+
+    let mut sys_ids = Vec::new();
+    let mut results = Vec::new();
+    for (index, (sys_id, input)) in batch.into_iter().enumerate()
+    {
+        sys_ids.push(sys_id);
+        match take_spawned_system(world, sys_id)
+        {
+            Ok((system, needs_init)) =>
+            {
+                results.push(Err(SysCallError::EmptySystem));
+                schedule.add_systems(
+                        BatchedSystem{
+                            index,
+                            system: Some(system),
+                            needs_init,
+                            input: Some(input),
+                            output: None,
+                            returned: returned.clone(),
+                        }
+                    );
+            }
+            Err(err) => results.push(Err(err)),
+        }
+    }
+
+    // run the systems
+    // - dropping the schedule returns the systems
+    schedule.run(world);
+    std::mem::drop(schedule);
+
+    let mut returned = std::mem::take(&mut *returned.lock().unwrap_or_else(|err| err.into_inner()));
+    returned.sort_unstable_by_key(|batch_return| batch_return.index);
+
+    // apply deferred commands in batch order
+    for batch_return in returned.iter_mut()
+    {
+        batch_return.system.apply_deferred(world);
+    }
+
+    // return the systems to their entities
+    for BatchReturn{ index, system, output } in returned
+    {
+        if let Some(output) = output { results[index] = Ok(output); }
+
+        let Some(mut entity_mut) = world.get_entity_mut(sys_ids[index].entity()) else { continue; };
+        let Some(mut spawned_system) = entity_mut.get_mut::<SpawnedSystem<I, O>>() else { continue; };
+        spawned_system.system = Some(CallbackSystem::Initialized(system));
+    }
+
+    results
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -9,6 +9,7 @@ use fxhash::FxHasher32;
 
 //standard shortcuts
 use std::any::TypeId;
+use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 
@@ -18,15 +19,21 @@ use std::marker::PhantomData;
 
 /// A system caller may have multiple instances. We need to ensure the local state of these instances is
 /// not shared. This hashmap allows us to dynamically store instance states.
+///
+/// Each call id maps to a stack of idle states. States are removed from the stack while running, so the world can be
+/// used freely during a call (including recursive calls, which get their own state).
 #[derive(Default, Resource)]
 struct StateInstances<T: SystemParam + 'static>
 {
-    instances: HashMap<CallId, SystemState<T>>,
+    instances: HashMap<CallId, Vec<SystemState<T>>>,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Stores idle instances of a system invoked with [`syscall()`].
+///
+/// Each concurrently-running (i.e. recursive) invocation uses its own instance, so instances are stored as a stack.
 #[derive(Resource)]
 struct InitializedSystem<I, O, S>
 where
@@ -34,8 +41,8 @@ where
     O: Send + Sync + 'static,
     S: Send + Sync + 'static
 {
-    sys      : BoxedSystem<I, O>,
-    _phantom : PhantomData<S>
+    instances : Vec<BoxedSystem<I, O>>,
+    _phantom  : PhantomData<S>
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -46,12 +53,12 @@ where
 /// We store the system in an option in order to avoid archetype moves when taking/reinserting the system in order to
 /// call it.
 #[derive(Component)]
-struct SpawnedSystem<I, O>
+pub(crate) struct SpawnedSystem<I, O>
 where
     I: Send + Sync + 'static,
     O: Send + Sync + 'static,
 {
-    system: Option<CallbackSystem<I, O>>,
+    pub(crate) system: Option<CallbackSystem<I, O>>,
 }
 
 impl<I, O> SpawnedSystem<I, O>
@@ -68,31 +75,45 @@ where
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn init_callable_system<S: SystemParam + 'static>(world: &mut World, id: CallId)
+fn take_callable_state<S: SystemParam + 'static>(world: &mut World, id: CallId) -> SystemState<S>
 {
-    // 1. obtain the callable system's existing state instances or make a new one
-    let mut state_instances =
-        match world.remove_resource::<StateInstances<S>>()
-        {
-            Some(s) => s,
-            None    =>
-            {
-                // Note, this message should only appear once! If you see it twice in the logs, the function
-                // may have been called recursively, and will panic.
-                debug!("Init system state {}", std::any::type_name::<S>());
-                StateInstances::<S>{instances: HashMap::new()}
-            }
-        };
+    // 1. obtain the callable system's existing state instances or make new ones
+    if !world.contains_resource::<StateInstances<S>>()
+    {
+        debug!("Init system state {}", std::any::type_name::<S>());
+        world.insert_resource(StateInstances::<S>{ instances: HashMap::new() });
+    }
+
+    // 2. take an idle state for this call id
+    // - if all states are running (we are in a recursive call), make a new state
+    let idle = world
+        .resource_mut::<StateInstances<S>>()
+        .instances
+        .get_mut(&id)
+        .and_then(|instances| instances.pop());
 
-    // 2. make sure our callable system has an instance for this call id
-    if !state_instances.instances.contains_key(&id)
+    match idle
     {
-        debug!("Registering system state for system caller {id:?} of type {}", std::any::type_name::<S>());
-        state_instances.instances.insert(id, SystemState::new(world));
+        Some(state) => state,
+        None =>
+        {
+            debug!("Registering system state for system caller {id:?} of type {}", std::any::type_name::<S>());
+            SystemState::new(world)
+        }
     }
+}
 
-    // 3. add the state instances to the world
-    world.insert_resource(state_instances);
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn put_back_callable_state<S: SystemParam + 'static>(world: &mut World, id: CallId, state: SystemState<S>)
+{
+    world
+        .get_resource_or_insert_with(|| StateInstances::<S>{ instances: HashMap::new() })
+        .instances
+        .entry(id)
+        .or_default()
+        .push(state);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -124,21 +145,30 @@ impl CallId
 
 /// Implemented types can be called like normal functions.
 /// - Accepts one function argument.
+/// - The world is not borrowed by the caller, so implementations can freely use `world` between accesses to `state`
+///   (e.g. to invoke other systems or call themselves recursively).
+///
+/// This trait can be derived for `SystemParam` structs, see [`CallableSystem`](derive@CallableSystem).
 pub trait CallableSystem: SystemParam
 {
     /// Parameter type that allows custom data to be passed from caller to the callable system.
     type ArgT;
+    /// Return type of the callable system.
+    type OutT;
 
     /// Implementation of the callable system.
-    fn system(world: &mut World, state: &mut SystemState<Self>, id: CallId, arg: Self::ArgT);
+    fn system(world: &mut World, state: &mut SystemState<Self>, id: CallId, arg: Self::ArgT) -> Self::OutT;
 }
 
 /// Implemented types can be called like normal functions.
 /// - accepts no function arguments
 pub trait BasicCallableSystem: SystemParam
 {
+    /// Return type of the callable system.
+    type OutT;
+
     /// Implementation of the callable system for callables with no arguments.
-    fn system(world: &mut World, state: &mut SystemState<Self>, id: CallId);
+    fn system(world: &mut World, state: &mut SystemState<Self>, id: CallId) -> Self::OutT;
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -167,8 +197,9 @@ pub trait BasicCallableSystem: SystemParam
 /// impl<'w, 's> CallableSystem for CallableTest<'w, 's>
 /// {
 ///     type ArgT = usize;
+///     type OutT = usize;
 /// 
-///     fn system(world: &mut World, state: &mut SystemState<Self>, _id: CallId, test_counter: usize)
+///     fn system(world: &mut World, state: &mut SystemState<Self>, _id: CallId, test_counter: usize) -> usize
 ///     {
 ///         // extract the system context
 ///         let mut context = state.get_mut(world);
@@ -176,32 +207,32 @@ pub trait BasicCallableSystem: SystemParam
 ///         // expect counter matches test_counter
 ///         assert_eq!(*context.counter.deref(), test_counter);
 ///         *context.counter = *context.counter + 1;
+///         *context.counter
 ///     }
 /// }
 /// 
 /// let mut world = World::new();
 /// 
-/// call::<CallableTest>(&mut world, CallId::new("a"), 0);
-/// call::<CallableTest>(&mut world, CallId::new("a"), 1);
+/// assert_eq!(call::<CallableTest>(&mut world, CallId::new("a"), 0), 1);
+/// assert_eq!(call::<CallableTest>(&mut world, CallId::new("a"), 1), 2);
 ///
-/// call::<CallableTest>(&mut world, CallId::new("b"), 0);
-/// call::<CallableTest>(&mut world, CallId::new("b"), 1);
+/// assert_eq!(call::<CallableTest>(&mut world, CallId::new("b"), 0), 1);
+/// assert_eq!(call::<CallableTest>(&mut world, CallId::new("b"), 1), 2);
 /// ```
 ///
-pub fn call<S: CallableSystem + 'static>(world: &mut World, id: CallId, arg: S::ArgT)
+pub fn call<S: CallableSystem + 'static>(world: &mut World, id: CallId, arg: S::ArgT) -> S::OutT
 {
-    // 1. make sure the callable system has been cached for this call id
-    init_callable_system::<S>(world, id);
+    // 1. take the callable system's cached state for this call id
+    let mut state = take_callable_state::<S>(world, id);
 
     // 2. call our cached system
-    world.resource_scope(
-            | world, mut states: Mut<StateInstances<S>> |
-            {
-                let cached_state = states.instances.get_mut(&id).unwrap();
-                S::system(world, cached_state, id, arg);
-                cached_state.apply(world);
-            }
-        );
+    let result = S::system(world, &mut state, id, arg);
+    state.apply(world);
+
+    // 3. put the state back
+    put_back_callable_state(world, id, state);
+
+    result
 }
 
 /// Call a callable system (no function arguments).
@@ -230,6 +261,8 @@ pub fn call<S: CallableSystem + 'static>(world: &mut World, id: CallId, arg: S::
 /// 
 /// impl<'w, 's> BasicCallableSystem for BasicCallableTest<'w, 's>
 /// {
+///     type OutT = ();
+///
 ///     fn system(world: &mut World, state: &mut SystemState<Self>, _id: CallId)
 ///     {
 ///         // extract the system context
@@ -253,29 +286,27 @@ pub fn call<S: CallableSystem + 'static>(world: &mut World, id: CallId, arg: S::
 /// assert_eq!(counter.0, 4);
 /// ```
 ///
-pub fn call_basic<S: BasicCallableSystem + 'static>(world: &mut World, id: CallId)
+pub fn call_basic<S: BasicCallableSystem + 'static>(world: &mut World, id: CallId) -> S::OutT
 {
-    // 1. make sure the callable system has been cached for this call id
-    init_callable_system::<S>(world, id);
+    // 1. take the callable system's cached state for this call id
+    let mut state = take_callable_state::<S>(world, id);
 
     // 2. call our cached system
-    world.resource_scope(
-            | world, mut states: Mut<StateInstances<S>> |
-            {
-                let cached_state = states.instances.get_mut(&id).unwrap();
-                S::system(world, cached_state, id);
-                cached_state.apply(world);
-            }
-        );
+    let result = S::system(world, &mut state, id);
+    state.apply(world);
+
+    // 3. put the state back
+    put_back_callable_state(world, id, state);
+
+    result
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Execute a system on some data then apply the system's deferred commands.
 ///
-/// # WARNING
-/// If a system is called recursively, the Local system parameters of all but the outer-most invocation will not
-/// persist.
+/// Recursive calls are supported. Each level of recursion uses its own system instance, so `Local` system parameters
+/// persist per recursion depth (e.g. the outer-most invocation always sees the same `Local` state).
 ///
 /// # Examples
 ///
@@ -312,27 +343,33 @@ where
     O: Send + Sync + 'static,
     S: IntoSystem<I, O, Marker> + Send + Sync + 'static,
 {
-    // get the initialized system
-    let mut system =
-        match world.remove_resource::<InitializedSystem<I, O, S>>()
+    // get an idle instance of the system
+    // - if all instances are running (we are in a recursive call), make a new instance
+    let mut sys =
+        match world
+            .get_resource_mut::<InitializedSystem<I, O, S>>()
+            .and_then(|mut system| system.instances.pop())
         {
-            Some(system) => system,
+            Some(sys) => sys,
             None =>
             {
                 let mut sys = IntoSystem::into_system(system);
                 sys.initialize(world);
-                InitializedSystem::<I, O, S>{ sys: Box::new(sys), _phantom: PhantomData::<S>{} }
+                Box::new(sys)
             }
         };
 
     // run the system
-    let result = system.sys.run(input, world);
+    let result = sys.run(input, world);
 
     // apply any pending changes
-    system.sys.apply_deferred(world);
+    sys.apply_deferred(world);
 
     // put the system back
-    world.insert_resource(system);
+    world
+        .get_resource_or_insert_with(|| InitializedSystem::<I, O, S>{ instances: Vec::new(), _phantom: PhantomData })
+        .instances
+        .push(sys);
 
     return result;
 }
@@ -356,18 +393,113 @@ where
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Error that can occur when calling or inserting systems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SysCallError
+{
+    /// The target entity does not exist.
+    MissingEntity(Entity),
+    /// The target entity does not contain a system with the requested input/output types.
+    MissingSystem(Entity),
+    /// No named system is registered with the requested name and input/output types.
+    MissingNamedSystem(SysName),
+    /// The system is currently running, so it was called recursively.
+    Recursive,
+    /// The system's callback is empty.
+    EmptySystem,
+}
+
+impl std::fmt::Display for SysCallError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            SysCallError::MissingEntity(entity)    => write!(f, "system entity {entity:?} does not exist"),
+            SysCallError::MissingSystem(entity)    =>
+            {
+                write!(f, "entity {entity:?} does not contain a system with the requested input/output types")
+            }
+            SysCallError::MissingNamedSystem(name) => write!(f, "named system {name:?} is not registered"),
+            SysCallError::Recursive                => write!(f, "system was called recursively"),
+            SysCallError::EmptySystem              => write!(f, "system callback is empty"),
+        }
+    }
+}
+
+impl std::error::Error for SysCallError {}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System identifier for referencing spawned systems.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct SysId(Entity);
+///
+/// The id carries the input and output types of the spawned system, so calling it with the wrong types is a
+/// compile error. Use [`AnySysId`] to store ids of systems with different signatures together.
+pub struct SysId<I = (), O = ()>
+{
+    entity   : Entity,
+    _phantom : PhantomData<fn(I) -> O>,
+}
+
+impl<I, O> SysId<I, O>
+{
+    /// Make a system id from a system entity.
+    ///
+    /// It is the responsibility of the caller to correctly match the system entity with the system signature.
+    pub fn new(entity: Entity) -> Self { Self{ entity, _phantom: PhantomData } }
+
+    pub fn entity(&self) -> Entity
+    {
+        self.entity
+    }
+
+    /// Erase the system's input and output types.
+    pub fn erase(&self) -> AnySysId
+    {
+        AnySysId(self.entity)
+    }
+}
 
-impl SysId
+impl<I, O> Clone for SysId<I, O> { fn clone(&self) -> Self { *self } }
+impl<I, O> Copy for SysId<I, O> {}
+impl<I, O> PartialEq for SysId<I, O> { fn eq(&self, other: &Self) -> bool { self.entity == other.entity } }
+impl<I, O> Eq for SysId<I, O> {}
+impl<I, O> Hash for SysId<I, O> { fn hash<H: Hasher>(&self, state: &mut H) { self.entity.hash(state); } }
+
+impl<I, O> std::fmt::Debug for SysId<I, O>
 {
-    pub fn new(entity: Entity) -> Self { Self(entity) }
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.debug_tuple("SysId").field(&self.entity).finish()
+    }
+}
+
+impl<I, O> From<SysId<I, O>> for AnySysId
+{
+    fn from(sys_id: SysId<I, O>) -> Self { sys_id.erase() }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Type-erased [`SysId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnySysId(Entity);
 
+impl AnySysId
+{
     pub fn entity(&self) -> Entity
     {
         self.0
     }
+
+    /// Recover the typed system id.
+    ///
+    /// It is the responsibility of the caller to correctly match the system entity with the system signature. Calling a
+    /// system with the wrong signature will fail at runtime.
+    pub fn typed<I, O>(&self) -> SysId<I, O>
+    {
+        SysId::new(self.0)
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -377,7 +509,7 @@ impl SysId
 /// Systems are not initialized until they are first run.
 ///
 /// The system can be invoked by calling [`spawned_syscall()`].
-pub fn spawn_system<I, O, S, Marker>(world: &mut World, system: S) -> SysId
+pub fn spawn_system<I, O, S, Marker>(world: &mut World, system: S) -> SysId<I, O>
 where
     I: Send + Sync + 'static,
     O: Send + Sync + 'static,
@@ -389,7 +521,7 @@ where
 /// Spawn a system as an entity.
 ///
 /// The system can be invoked by calling [`spawned_syscall()`].
-pub fn spawn_system_from<I, O>(world: &mut World, system: CallbackSystem<I, O>) -> SysId
+pub fn spawn_system_from<I, O>(world: &mut World, system: CallbackSystem<I, O>) -> SysId<I, O>
 where
     I: Send + Sync + 'static,
     O: Send + Sync + 'static,
@@ -426,14 +558,16 @@ where
     O: Send + Sync + 'static,
 {
     let sys_id = spawn_system_from(world, system);
-    world.resource::<AutoDespawner>().prepare(sys_id.0)
+    world.resource::<AutoDespawner>().prepare(sys_id.entity())
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Execute a pre-spawned system on some data then apply the system's deferred commands.
 ///
-/// Returns `Err` if the system does not exist or if the system was called recursively.
+/// Returns `Err` if the system does not exist, if the system entity doesn't hold a system with the expected
+/// input/output types, or if the system was called recursively. Spawned systems only have one instance, so recursive
+/// calls always return [`SysCallError::Recursive`] instead of running.
 ///
 /// # Example
 ///
@@ -457,23 +591,23 @@ where
 /// assert_eq!(spawned_syscall(&mut world, sys_id2, 10u16), 20);
 /// ```
 ///
-pub fn spawned_syscall<I, O>(world: &mut World, sys_id: SysId, input: I) -> Result<O, ()>
+pub fn spawned_syscall<I, O>(world: &mut World, sys_id: SysId<I, O>, input: I) -> Result<O, SysCallError>
 where
     I: Send + Sync + 'static,
     O: Send + Sync + 'static,
 {
     // extract the callback
-    let Some(mut entity_mut) = world.get_entity_mut(sys_id.0) else { return Err(()); };
+    let entity = sys_id.entity();
+    let Some(mut entity_mut) = world.get_entity_mut(entity) else { return Err(SysCallError::MissingEntity(entity)); };
     let Some(mut spawned_system) = entity_mut.get_mut::<SpawnedSystem<I, O>>()
-    else { tracing::error!(?sys_id, "spawned system component is missing"); return Err(()); };
-    let Some(mut callback) = spawned_system.system.take()
-    else { tracing::warn!(?sys_id, "recursive spawned system call detected"); return Err(()); };
+    else { return Err(SysCallError::MissingSystem(entity)); };
+    let Some(mut callback) = spawned_system.system.take() else { return Err(SysCallError::Recursive); };
 
     // invoke the callback
-    let result = callback.run(world, input).ok_or(())?;
+    let result = callback.run(world, input).ok_or(SysCallError::EmptySystem)?;
 
     // reinsert the callback if its target hasn't been despawned
-    let Some(mut entity_mut) = world.get_entity_mut(sys_id.0) else { return Ok(result); };
+    let Some(mut entity_mut) = world.get_entity_mut(sys_id.entity()) else { return Ok(result); };
     let Some(mut spawned_system) = entity_mut.get_mut::<SpawnedSystem<I, O>>()
     else { tracing::error!(?sys_id, "spawned system component is missing"); return Ok(result); };
     spawned_system.system = Some(callback);
@@ -491,7 +625,7 @@ pub trait SystemCallerCommandsExt
     ///
     /// Returns the system id that will eventually reference the spawned system. It can be used to invoke the system with
     /// [`spawned_syscall()`] or [`SystemCallerCommandsExt::spawned_syscall()`].
-    fn spawn_system<I, O, S, Marker>(&mut self, system: S) -> SysId
+    fn spawn_system<I, O, S, Marker>(&mut self, system: S) -> SysId<I, O>
     where
         I: Send + Sync + 'static,
         O: Send + Sync + 'static,
@@ -501,7 +635,7 @@ pub trait SystemCallerCommandsExt
     ///
     /// Returns the system id that will eventually reference the spawned system. It can be used to invoke the system with
     /// [`spawned_syscall()`] or [`SystemCallerCommandsExt::spawned_syscall()`].
-    fn spawn_system_from<I, O>(&mut self, system: CallbackSystem<I, O>) -> SysId
+    fn spawn_system_from<I, O>(&mut self, system: CallbackSystem<I, O>) -> SysId<I, O>
     where
         I: Send + Sync + 'static,
         O: Send + Sync + 'static;
@@ -512,7 +646,7 @@ pub trait SystemCallerCommandsExt
     /// up.
     ///
     /// Returns an error if the entity does not exist.
-    fn insert_system<I, O, S, Marker>(&mut self, entity: Entity, system: S) -> Result<(), ()>
+    fn insert_system<I, O, S, Marker>(&mut self, entity: Entity, system: S) -> Result<(), SysCallError>
     where
         I: Send + Sync + 'static,
         O: Send + Sync + 'static,
@@ -528,19 +662,17 @@ pub trait SystemCallerCommandsExt
 
     /// Schedule a spawned system call.
     ///
-    /// It is the responsibility of the caller to correctly match the system entity with the target system signature.
-    ///
-    /// Logs a warning if the system entity doesn't exist.
+    /// Logs a warning if the call fails.
     ///
     /// Syntax sugar for [`spawned_syscall()`].
-    fn spawned_syscall<I>(&mut self, sys_id: SysId, input: I)
+    fn spawned_syscall<I>(&mut self, sys_id: SysId<I, ()>, input: I)
     where
         I: Send + Sync + 'static;
 }
 
 impl<'w, 's> SystemCallerCommandsExt for Commands<'w, 's>
 {
-    fn spawn_system<I, O, S, Marker>(&mut self, system: S) -> SysId
+    fn spawn_system<I, O, S, Marker>(&mut self, system: S) -> SysId<I, O>
     where
         I: Send + Sync + 'static,
         O: Send + Sync + 'static,
@@ -549,7 +681,7 @@ impl<'w, 's> SystemCallerCommandsExt for Commands<'w, 's>
         self.spawn_system_from(CallbackSystem::new(system))
     }
 
-    fn spawn_system_from<I, O>(&mut self, system: CallbackSystem<I, O>) -> SysId
+    fn spawn_system_from<I, O>(&mut self, system: CallbackSystem<I, O>) -> SysId<I, O>
     where
         I: Send + Sync + 'static,
         O: Send + Sync + 'static
@@ -557,13 +689,13 @@ impl<'w, 's> SystemCallerCommandsExt for Commands<'w, 's>
         SysId::new(self.spawn(SpawnedSystem::new(system)).id())
     }
 
-    fn insert_system<I, O, S, Marker>(&mut self, entity: Entity, system: S) -> Result<(), ()>
+    fn insert_system<I, O, S, Marker>(&mut self, entity: Entity, system: S) -> Result<(), SysCallError>
     where
         I: Send + Sync + 'static,
         O: Send + Sync + 'static,
         S: IntoSystem<I, O, Marker> + Send + Sync + 'static
     {
-        let Some(mut entity) = self.get_entity(entity) else { return Err(()); };
+        let Some(mut entity) = self.get_entity(entity) else { return Err(SysCallError::MissingEntity(entity)); };
         entity.insert(SpawnedSystem::new(CallbackSystem::new(system)));
 
         Ok(())
@@ -577,16 +709,16 @@ impl<'w, 's> SystemCallerCommandsExt for Commands<'w, 's>
         self.add(move |world: &mut World| syscall(world, input, system));
     }
 
-    fn spawned_syscall<I>(&mut self, sys_id: SysId, input: I)
+    fn spawned_syscall<I>(&mut self, sys_id: SysId<I, ()>, input: I)
     where
         I: Send + Sync + 'static,
     {
         self.add(
                 move |world: &mut World|
                 {
-                    if let Err(_) = spawned_syscall::<I, ()>(world, sys_id, input)
+                    if let Err(err) = spawned_syscall(world, sys_id, input)
                     {
-                        tracing::warn!(?sys_id, "spawned syscall failed");
+                        tracing::warn!(?sys_id, ?err, "spawned syscall failed");
                     }
                 }
             );
@@ -599,9 +731,8 @@ impl<'w, 's> SystemCallerCommandsExt for Commands<'w, 's>
 ///
 /// Systems with different names will have different Local state.
 ///
-/// # WARNING
-/// If a system is called recursively, the Local system parameters of all but the outer-most invocation will not
-/// persist.
+/// Recursive calls are supported. Each level of recursion uses its own system instance, so `Local` system parameters
+/// persist per recursion depth.
 ///
 /// # Examples
 ///
@@ -638,21 +769,18 @@ where
     // the system id
     let sys_name = SysName::new::<S>(id);
 
-    // get resource storing the id-mapped systems
-    let mut id_mapped_systems = world.get_resource_or_insert_with::<IdMappedSystems<I, O>>(
-            || IdMappedSystems::default()
-        );
-
-    // take the initialized system
-    let mut system =
-        match id_mapped_systems.systems.get_mut(&sys_name).map_or(None, |node| node.take())
+    // take an idle instance of the system
+    // - if all instances are running (we are in a recursive call), make a new instance
+    let (mut system, generation) =
+        match id_mapped_systems_mut::<I, O>(world).take(sys_name)
         {
-            Some(system) => system,
+            Some(taken) => taken,
             None =>
             {
                 let mut sys = IntoSystem::into_system(system);
                 sys.initialize(world);
-                Box::new(sys)
+                let generation = world.resource_mut::<IdMappedSystems<I, O>>().add_generation(sys_name, sys.name());
+                (Box::new(sys) as BoxedSystem<I, O>, generation)
             }
         };
 
@@ -662,42 +790,24 @@ where
     // apply any pending changes
     system.apply_deferred(world);
 
-    // re-acquire mutable access to id-mapped systems
-    let mut id_mapped_systems = world.get_resource_or_insert_with::<IdMappedSystems<I, O>>(
-            || IdMappedSystems::default()
-        );
-
     // put the system back
-    // - we ignore overwrites
-    match id_mapped_systems.systems.get_mut(&sys_name)
-    {
-        Some(node) => { let _ = node.replace(system); },
-        None       => { let _ = id_mapped_systems.systems.insert(sys_name, Some(system)); },
-    }
+    id_mapped_systems_mut::<I, O>(world).put_back(sys_name, generation, system);
 
     result
 }
 
 /// Directly invoke a named system.
 ///
-/// Returns `Err` if the system cannot be found.
-pub fn named_syscall_direct<I, O>(world: &mut World, sys_name: SysName, input: I) -> Result<O, ()>
+/// Returns `Err` if the system cannot be found or if all instances of the system are running (i.e. the system was
+/// called recursively). Registered systems only have one instance, since new instances can't be made from an
+/// already-constructed system.
+pub fn named_syscall_direct<I, O>(world: &mut World, sys_name: SysName, input: I) -> Result<O, SysCallError>
 where
     I: Send + Sync + 'static,
     O: Send + Sync + 'static,
 {
-    // get resource storing the id-mapped systems
-    let mut id_mapped_systems = world.get_resource_or_insert_with::<IdMappedSystems<I, O>>(
-            || IdMappedSystems::default()
-        );
-
     // take the initialized system
-    let mut system =
-        match id_mapped_systems.systems.get_mut(&sys_name).map_or(None, |node| node.take())
-        {
-            Some(system) => system,
-            None => return Err(()),
-        };
+    let (mut system, generation) = id_mapped_systems_mut::<I, O>(world).try_take(sys_name)?;
 
     // run the system
     let result = system.run(input, world);
@@ -705,25 +815,16 @@ where
     // apply any pending changes
     system.apply_deferred(world);
 
-    // re-acquire mutable access to id-mapped systems
-    let mut id_mapped_systems = world.get_resource_or_insert_with::<IdMappedSystems<I, O>>(
-            || IdMappedSystems::default()
-        );
-
     // put the system back
-    // - we ignore overwrites
-    match id_mapped_systems.systems.get_mut(&sys_name)
-    {
-        Some(node) => { let _ = node.replace(system); },
-        None       => { let _ = id_mapped_systems.systems.insert(sys_name, Some(system)); },
-    }
+    id_mapped_systems_mut::<I, O>(world).put_back(sys_name, generation, system);
 
     Ok(result)
 }
 
 /// Register a named system for future use.
 ///
-/// Over-writes the existing system with the same id and type, if one exists.
+/// Over-writes the existing system with the same id and type, if one exists. Instances of the previous system that
+/// are currently running will be dropped when they finish.
 ///
 /// Useful for inserting a closure-type system that captures non-Copy data when you need to invoke the system
 /// multiple times.
@@ -749,17 +850,8 @@ where
     // initialize the callback
     let Some(boxed_system) = callback.take_initialized(world) else { return; };
 
-    // get resource storing the id-mapped systems
-    let mut id_mapped_systems = world.get_resource_or_insert_with::<IdMappedSystems<I, O>>(
-        || IdMappedSystems::default()
-    );
-
     // insert the system
-    match id_mapped_systems.systems.get_mut(&sys_name)
-    {
-        Some(node) => { let _ = node.replace(boxed_system); },
-        None       => { let _ = id_mapped_systems.systems.insert(sys_name, Some(boxed_system)); },
-    }
+    id_mapped_systems_mut::<I, O>(world).replace(sys_name, boxed_system);
 }
 
 /// System identifier for use in named systems.
@@ -791,14 +883,32 @@ impl SysName
     }
 }
 
+/// Instances of a named system.
+struct NamedSystemInstances<I, O>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    /// Changed when the system is replaced, so running instances of old systems can be discarded.
+    generation: u64,
+    /// Type name of the system.
+    system_type: Cow<'static, str>,
+    /// Instances that are not currently running.
+    idle: Vec<BoxedSystem<I, O>>,
+}
+
 /// Tracks named systems.
+///
+/// Each name maps to a stack of idle system instances. Instances are removed from the stack while running.
 #[derive(Resource)]
 pub struct IdMappedSystems<I, O>
 where
     I: Send + Sync + 'static,
     O: Send + Sync + 'static,
 {
-    systems: HashMap<SysName, Option<BoxedSystem<I, O>>>,
+    systems: HashMap<SysName, NamedSystemInstances<I, O>>,
+    /// Generation source (generations are unique so revoked-then-reregistered systems can't receive stale instances).
+    generation_counter: u64,
 }
 
 impl<I, O> IdMappedSystems<I, O>
@@ -806,16 +916,90 @@ where
     I: Send + Sync + 'static,
     O: Send + Sync + 'static,
 {
+    /// Revoke a named system.
+    ///
+    /// Instances that are currently running will be dropped when they finish.
     pub fn revoke<S: 'static>(&mut self, id: impl Hash)
     {
         let id = SysName::new::<S>(id);
         let _ = self.systems.remove(&id);
     }
 
+    /// See [`Self::revoke()`].
     pub fn revoke_sysname(&mut self, id: SysName)
     {
         let _ = self.systems.remove(&id);
     }
+
+    /// Check if a named system is registered.
+    pub fn contains(&self, id: SysName) -> bool
+    {
+        self.systems.contains_key(&id)
+    }
+
+    /// Iterate over info about registered named systems.
+    pub fn iter(&self) -> impl Iterator<Item = NamedSystemInfo> + '_
+    {
+        self.systems
+            .iter()
+            .map(
+                |(sys_name, instances)|
+                NamedSystemInfo{
+                    name        : *sys_name,
+                    system_type : instances.system_type.clone(),
+                    input_type  : std::any::type_name::<I>(),
+                    output_type : std::any::type_name::<O>(),
+                }
+            )
+    }
+
+    /// Take an idle instance and its generation.
+    fn take(&mut self, sys_name: SysName) -> Option<(BoxedSystem<I, O>, u64)>
+    {
+        let instances = self.systems.get_mut(&sys_name)?;
+        Some((instances.idle.pop()?, instances.generation))
+    }
+
+    /// Take an idle instance and its generation.
+    /// - Returns `Err` if there is no idle instance.
+    fn try_take(&mut self, sys_name: SysName) -> Result<(BoxedSystem<I, O>, u64), SysCallError>
+    {
+        let Some(instances) = self.systems.get_mut(&sys_name) else { return Err(SysCallError::MissingNamedSystem(sys_name)); };
+        let system = instances.idle.pop().ok_or(SysCallError::Recursive)?;
+        Ok((system, instances.generation))
+    }
+
+    /// Get the generation for a new instance.
+    fn add_generation(&mut self, sys_name: SysName, system_type: Cow<'static, str>) -> u64
+    {
+        if let Some(instances) = self.systems.get(&sys_name) { return instances.generation; }
+        let generation = self.next_generation();
+        self.systems.insert(sys_name, NamedSystemInstances{ generation, system_type, idle: Vec::new() });
+        generation
+    }
+
+    /// Return an instance after it runs.
+    /// - The instance is dropped if the system was replaced or revoked while the instance was running.
+    fn put_back(&mut self, sys_name: SysName, generation: u64, system: BoxedSystem<I, O>)
+    {
+        let Some(instances) = self.systems.get_mut(&sys_name) else { return; };
+        if instances.generation != generation { return; }
+        instances.idle.push(system);
+    }
+
+    /// Replace all instances of a system.
+    fn replace(&mut self, sys_name: SysName, system: BoxedSystem<I, O>)
+    {
+        let generation = self.next_generation();
+        let system_type = system.name();
+        self.systems.insert(sys_name, NamedSystemInstances{ generation, system_type, idle: vec![system] });
+    }
+
+    fn next_generation(&mut self) -> u64
+    {
+        self.generation_counter += 1;
+        self.generation_counter
+    }
 }
 
 impl<I, O> Default for IdMappedSystems<I, O>
@@ -823,7 +1007,115 @@ where
     I: Send + Sync + 'static,
     O: Send + Sync + 'static,
 {
-    fn default() -> Self { Self{ systems: HashMap::default() } }
+    fn default() -> Self { Self{ systems: HashMap::default(), generation_counter: 0 } }
+}
+
+/// Get the named systems with input `I` and output `O`, inserting them if missing.
+fn id_mapped_systems_mut<I, O>(world: &mut World) -> Mut<'_, IdMappedSystems<I, O>>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    if !world.contains_resource::<IdMappedSystems<I, O>>()
+    {
+        world.init_resource::<IdMappedSystems<I, O>>();
+        world.get_resource_or_insert_with(NamedSystemRegistry::default).add::<I, O>();
+    }
+    world.resource_mut::<IdMappedSystems<I, O>>()
+}
+
+fn collect_named_systems<I, O>(world: &World, infos: &mut Vec<NamedSystemInfo>)
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    let Some(systems) = world.get_resource::<IdMappedSystems<I, O>>() else { return; };
+    infos.extend(systems.iter());
+}
+
+fn contains_named_system<I, O>(world: &World, sys_name: SysName) -> bool
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    world.get_resource::<IdMappedSystems<I, O>>().is_some_and(|systems| systems.contains(sys_name))
+}
+
+/// Type-erased access to every [`IdMappedSystems`] in the world, so named systems can be inspected across all
+/// input/output types.
+#[derive(Resource, Default)]
+struct NamedSystemRegistry
+{
+    collect  : Vec<fn(&World, &mut Vec<NamedSystemInfo>)>,
+    contains : Vec<fn(&World, SysName) -> bool>,
+}
+
+impl NamedSystemRegistry
+{
+    fn add<I, O>(&mut self)
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+    {
+        self.collect.push(collect_named_systems::<I, O>);
+        self.contains.push(contains_named_system::<I, O>);
+    }
+}
+
+/// Information about a registered named system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedSystemInfo
+{
+    /// The system's name.
+    pub name: SysName,
+    /// Type name of the system's current implementation.
+    pub system_type: Cow<'static, str>,
+    /// Type name of the system's input.
+    pub input_type: &'static str,
+    /// Type name of the system's output.
+    pub output_type: &'static str,
+}
+
+/// List all registered named systems across all input/output types.
+///
+/// The order of the list is unspecified.
+pub fn named_systems(world: &World) -> Vec<NamedSystemInfo>
+{
+    let mut infos = Vec::new();
+    let Some(registry) = world.get_resource::<NamedSystemRegistry>() else { return infos; };
+    for collect in registry.collect.iter() { collect(world, &mut infos); }
+    infos
+}
+
+/// Check if a named system is registered for any input/output types.
+pub fn is_named_system_registered(world: &World, sys_name: SysName) -> bool
+{
+    let Some(registry) = world.get_resource::<NamedSystemRegistry>() else { return false; };
+    registry.contains.iter().any(|contains| contains(world, sys_name))
+}
+
+/// Replace the implementation of a registered named system while keeping its name.
+///
+/// The new system starts with fresh `Local` state. Instances of the previous system that are currently running will
+/// be dropped when they finish.
+///
+/// Note that [`named_syscall()`] constructs new instances from the system passed to it if all registered instances
+/// are running (i.e. in recursive calls).
+///
+/// Returns an error if no named system with this name and input/output types is registered.
+pub fn replace_named_system<I, O, S, Marker>(world: &mut World, sys_name: SysName, system: S) -> Result<(), SysCallError>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+    S: IntoSystem<I, O, Marker> + Send + Sync + 'static,
+{
+    let is_registered = world
+        .get_resource::<IdMappedSystems<I, O>>()
+        .is_some_and(|systems| systems.contains(sys_name));
+    if !is_registered { return Err(SysCallError::MissingNamedSystem(sys_name)); }
+
+    register_named_system(world, sys_name, system);
+    Ok(())
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,315 @@
+//local shortcuts
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marker for plain systems used as [`SysCallStage`]s.
+pub struct IsSystemStage;
+
+/// Marker for spawned systems used as [`SysCallStage`]s.
+pub struct IsSpawnedStage;
+
+/// Marker for named systems used as [`SysCallStage`]s.
+pub struct IsNamedStage;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A stage of a pipe passed to [`spawned_syscall_pipe()`] or [`spawned_syscall_try_pipe()`].
+///
+/// Implemented for plain systems (invoked with [`syscall()`]), [`SysId`] (invoked with [`spawned_syscall()`]), and
+/// [`SysName`] (invoked with [`named_syscall_direct()`]).
+pub trait SysCallStage<I, O, Marker>: Send + Sync + 'static
+{
+    /// Invoke the stage.
+    fn call_stage(self, world: &mut World, input: I) -> Result<O, SysCallError>;
+}
+
+impl<I, O, S, Marker> SysCallStage<I, O, (IsSystemStage, Marker)> for S
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+    S: IntoSystem<I, O, Marker> + Send + Sync + 'static,
+{
+    fn call_stage(self, world: &mut World, input: I) -> Result<O, SysCallError>
+    {
+        Ok(syscall(world, input, self))
+    }
+}
+
+impl<I, O> SysCallStage<I, O, IsSpawnedStage> for SysId<I, O>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    fn call_stage(self, world: &mut World, input: I) -> Result<O, SysCallError>
+    {
+        spawned_syscall(world, self, input)
+    }
+}
+
+impl<I, O> SysCallStage<I, O, IsNamedStage> for SysName
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    fn call_stage(self, world: &mut World, input: I) -> Result<O, SysCallError>
+    {
+        named_syscall_direct(world, self, input)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A tuple of systems that can be run with [`syscall_pipe()`].
+pub trait SysPipe<I, O, Marker>
+{
+    /// Run the pipe.
+    fn run_pipe(self, world: &mut World, input: I) -> O;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A tuple of `Result`-returning systems that can be run with [`syscall_try_pipe()`].
+pub trait TrySysPipe<I, O, E, Marker>
+{
+    /// Run the pipe.
+    fn run_try_pipe(self, world: &mut World, input: I) -> Result<O, E>;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A tuple of [`SysCallStage`]s that can be run with [`spawned_syscall_pipe()`].
+pub trait SysCallPipe<I, O, Marker>
+{
+    /// Run the pipe.
+    fn run_pipe(self, world: &mut World, input: I) -> Result<O, SysCallError>;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A tuple of `Result`-returning [`SysCallStage`]s that can be run with [`spawned_syscall_try_pipe()`].
+pub trait TrySysCallPipe<I, O, E, Marker>
+{
+    /// Run the pipe.
+    fn run_try_pipe(self, world: &mut World, input: I) -> Result<O, E>;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+impl<I, O, S, M> SysPipe<I, O, (M,)> for (S,)
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+    S: IntoSystem<I, O, M> + Send + Sync + 'static,
+{
+    fn run_pipe(self, world: &mut World, input: I) -> O
+    {
+        syscall(world, input, self.0)
+    }
+}
+
+impl<I, O, E, S, M> TrySysPipe<I, O, E, (M,)> for (S,)
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+    S: IntoSystem<I, Result<O, E>, M> + Send + Sync + 'static,
+{
+    fn run_try_pipe(self, world: &mut World, input: I) -> Result<O, E>
+    {
+        syscall(world, input, self.0)
+    }
+}
+
+impl<I, O, S, M> SysCallPipe<I, O, (M,)> for (S,)
+where
+    S: SysCallStage<I, O, M>,
+{
+    fn run_pipe(self, world: &mut World, input: I) -> Result<O, SysCallError>
+    {
+        self.0.call_stage(world, input)
+    }
+}
+
+impl<I, O, E, S, M> TrySysCallPipe<I, O, E, (M,)> for (S,)
+where
+    E: From<SysCallError>,
+    S: SysCallStage<I, Result<O, E>, M>,
+{
+    fn run_try_pipe(self, world: &mut World, input: I) -> Result<O, E>
+    {
+        self.0.call_stage(world, input)?
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Implements the pipe traits for a tuple by running the first stage then passing its output to the rest of the tuple.
+macro_rules! impl_sys_pipes
+{
+    ($s1:ident) => {};
+    ($s1:ident, $($s:ident),+) =>
+    {
+        impl<I, O, O1, M1, MRest, $s1, $($s),+> SysPipe<I, O, (M1, O1, MRest)> for ($s1, $($s),+)
+        where
+            I: Send + Sync + 'static,
+            O1: Send + Sync + 'static,
+            $s1: IntoSystem<I, O1, M1> + Send + Sync + 'static,
+            ($($s,)+): SysPipe<O1, O, MRest>,
+        {
+            #[allow(non_snake_case)]
+            fn run_pipe(self, world: &mut World, input: I) -> O
+            {
+                let ($s1, $($s),+) = self;
+                let output = syscall(world, input, $s1);
+                ($($s,)+).run_pipe(world, output)
+            }
+        }
+
+        impl<I, O, O1, E, M1, MRest, $s1, $($s),+> TrySysPipe<I, O, E, (M1, O1, MRest)> for ($s1, $($s),+)
+        where
+            I: Send + Sync + 'static,
+            O1: Send + Sync + 'static,
+            E: Send + Sync + 'static,
+            $s1: IntoSystem<I, Result<O1, E>, M1> + Send + Sync + 'static,
+            ($($s,)+): TrySysPipe<O1, O, E, MRest>,
+        {
+            #[allow(non_snake_case)]
+            fn run_try_pipe(self, world: &mut World, input: I) -> Result<O, E>
+            {
+                let ($s1, $($s),+) = self;
+                let output = syscall(world, input, $s1)?;
+                ($($s,)+).run_try_pipe(world, output)
+            }
+        }
+
+        impl<I, O, O1, M1, MRest, $s1, $($s),+> SysCallPipe<I, O, (M1, O1, MRest)> for ($s1, $($s),+)
+        where
+            $s1: SysCallStage<I, O1, M1>,
+            ($($s,)+): SysCallPipe<O1, O, MRest>,
+        {
+            #[allow(non_snake_case)]
+            fn run_pipe(self, world: &mut World, input: I) -> Result<O, SysCallError>
+            {
+                let ($s1, $($s),+) = self;
+                let output = $s1.call_stage(world, input)?;
+                ($($s,)+).run_pipe(world, output)
+            }
+        }
+
+        impl<I, O, O1, E, M1, MRest, $s1, $($s),+> TrySysCallPipe<I, O, E, (M1, O1, MRest)> for ($s1, $($s),+)
+        where
+            E: From<SysCallError>,
+            $s1: SysCallStage<I, Result<O1, E>, M1>,
+            ($($s,)+): TrySysCallPipe<O1, O, E, MRest>,
+        {
+            #[allow(non_snake_case)]
+            fn run_try_pipe(self, world: &mut World, input: I) -> Result<O, E>
+            {
+                let ($s1, $($s),+) = self;
+                let output = $s1.call_stage(world, input)??;
+                ($($s,)+).run_try_pipe(world, output)
+            }
+        }
+
+        impl_sys_pipes!($($s),+);
+    };
+}
+
+impl_sys_pipes!(S1, S2, S3, S4, S5, S6, S7, S8);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Execute a chain of systems, passing the output of each system to the next.
+///
+/// Each stage is invoked with [`syscall()`], so deferred commands are applied between stages.
+///
+/// # Example
+///
+/// ```
+/// use bevy_kot_ecs::*;
+/// use bevy::prelude::*;
+///
+/// let mut world = World::new();
+/// let result = syscall_pipe(&mut world, 2u16,
+///         (
+///             |In(x): In<u16>| x + 1,
+///             |In(x): In<u16>| x * 10,
+///             |In(x): In<u16>| x.to_string(),
+///         )
+///     );
+/// assert_eq!(result, "30");
+/// ```
+pub fn syscall_pipe<I, O, P, Marker>(world: &mut World, input: I, pipe: P) -> O
+where
+    P: SysPipe<I, O, Marker>,
+{
+    pipe.run_pipe(world, input)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Execute a chain of `Result`-returning systems, passing the `Ok` output of each system to the next.
+///
+/// Returns the first error encountered without running the remaining stages. Deferred commands are applied between
+/// stages, including for the stage that failed.
+pub fn syscall_try_pipe<I, O, E, P, Marker>(world: &mut World, input: I, pipe: P) -> Result<O, E>
+where
+    P: TrySysPipe<I, O, E, Marker>,
+{
+    pipe.run_try_pipe(world, input)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Execute a chain of [`SysCallStage`]s, passing the output of each stage to the next.
+///
+/// Stages may be a mix of plain systems, spawned systems ([`SysId`]), and named systems ([`SysName`]). Deferred commands
+/// are applied between stages.
+///
+/// Returns the first [`SysCallError`] encountered without running the remaining stages.
+///
+/// # Example
+///
+/// ```
+/// use bevy_kot_ecs::*;
+/// use bevy::prelude::*;
+///
+/// let mut world = World::new();
+/// let double = spawn_system(&mut world, |In(x): In<u16>| x * 2);
+/// let sys_name = SysName::new::<u16>("add_one");
+/// register_named_system(&mut world, sys_name, |In(x): In<u16>| x + 1);
+///
+/// let result = spawned_syscall_pipe(&mut world, 3u16, (double, sys_name, |In(x): In<u16>| x * 10));
+/// assert_eq!(result, Ok(70u16));
+/// ```
+pub fn spawned_syscall_pipe<I, O, P, Marker>(world: &mut World, input: I, pipe: P) -> Result<O, SysCallError>
+where
+    P: SysCallPipe<I, O, Marker>,
+{
+    pipe.run_pipe(world, input)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Execute a chain of `Result`-returning [`SysCallStage`]s, passing the `Ok` output of each stage to the next.
+///
+/// Returns the first error encountered without running the remaining stages. Failures to invoke a stage are converted
+/// to the stages' error type.
+pub fn spawned_syscall_try_pipe<I, O, E, P, Marker>(world: &mut World, input: I, pipe: P) -> Result<O, E>
+where
+    E: From<SysCallError>,
+    P: TrySysCallPipe<I, O, E, Marker>,
+{
+    pipe.run_try_pipe(world, input)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,149 @@
+//local shortcuts
+use crate::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParamItem;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::marker::PhantomData;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Check if any of `buttons` has one of the `button_types`.
+fn any_gamepad_button<'a>(
+    mut buttons  : impl Iterator<Item = &'a GamepadButton>,
+    button_types : &[GamepadButtonType],
+) -> bool
+{
+    buttons.any(|button| button_types.contains(&button.button_type))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Keyboard interaction source. Any of the `keys` act as a click.
+/// - Intended for use with a focus cursor (see [`FocusAppExt::register_focus_cursor()`]).
+/// - Defaults to `Enter` and `Space`.
+#[derive(Resource, Debug)]
+pub struct KeyboardConfirm<U: LunexUi, C: LunexCursor>
+{
+    pub keys : Vec<KeyCode>,
+    _phantom : PhantomData<(U, C)>,
+}
+
+impl<U: LunexUi, C: LunexCursor> KeyboardConfirm<U, C>
+{
+    pub fn new(keys: Vec<KeyCode>) -> Self { Self{ keys, _phantom: PhantomData } }
+}
+
+impl<U: LunexUi, C: LunexCursor> Default for KeyboardConfirm<U, C>
+{ fn default() -> Self { Self::new(vec![KeyCode::Return, KeyCode::Space]) } }
+
+impl<U: LunexUi, C: LunexCursor> InteractionSource for KeyboardConfirm<U, C>
+{
+    type SourceParam = SRes<Input<KeyCode>>;
+    type LunexUi     = U;
+    type LunexCursor = C;
+
+    fn just_clicked(&self, source: &SystemParamItem<SRes<Input<KeyCode>>>) -> bool
+    {
+        source.any_just_pressed(self.keys.iter().copied())
+    }
+    fn is_clicked(&self, source: &SystemParamItem<SRes<Input<KeyCode>>>) -> bool
+    {
+        source.any_pressed(self.keys.iter().copied())
+    }
+    fn just_unclicked(&self, source: &SystemParamItem<SRes<Input<KeyCode>>>) -> bool
+    {
+        source.any_just_released(self.keys.iter().copied())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gamepad interaction source. Any of the `buttons` on any gamepad act as a click.
+/// - Intended for use with a focus cursor (see [`FocusAppExt::register_focus_cursor()`]).
+/// - Defaults to [`GamepadButtonType::South`] (the `A` button on Xbox controllers).
+#[derive(Resource, Debug)]
+pub struct GamepadConfirm<U: LunexUi, C: LunexCursor>
+{
+    pub buttons : Vec<GamepadButtonType>,
+    _phantom    : PhantomData<(U, C)>,
+}
+
+impl<U: LunexUi, C: LunexCursor> GamepadConfirm<U, C>
+{
+    pub fn new(buttons: Vec<GamepadButtonType>) -> Self { Self{ buttons, _phantom: PhantomData } }
+}
+
+impl<U: LunexUi, C: LunexCursor> Default for GamepadConfirm<U, C>
+{ fn default() -> Self { Self::new(vec![GamepadButtonType::South]) } }
+
+impl<U: LunexUi, C: LunexCursor> InteractionSource for GamepadConfirm<U, C>
+{
+    type SourceParam = SRes<Input<GamepadButton>>;
+    type LunexUi     = U;
+    type LunexCursor = C;
+
+    fn just_clicked(&self, source: &SystemParamItem<SRes<Input<GamepadButton>>>) -> bool
+    {
+        any_gamepad_button(source.get_just_pressed(), &self.buttons)
+    }
+    fn is_clicked(&self, source: &SystemParamItem<SRes<Input<GamepadButton>>>) -> bool
+    {
+        any_gamepad_button(source.get_pressed(), &self.buttons)
+    }
+    fn just_unclicked(&self, source: &SystemParamItem<SRes<Input<GamepadButton>>>) -> bool
+    {
+        any_gamepad_button(source.get_just_released(), &self.buttons)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Move focus with the keyboard.
+/// - `Tab`/`Shift + Tab`: next/previous element in tab order.
+/// - Arrow keys: directional navigation.
+pub fn keyboard_focus_navigation<U: LunexUi, C: LunexCursor>(mut commands: Commands, keys: Res<Input<KeyCode>>)
+{
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let directions = [
+            (KeyCode::Tab,   if shift { FocusDirection::Previous } else { FocusDirection::Next }),
+            (KeyCode::Up,    FocusDirection::Up),
+            (KeyCode::Down,  FocusDirection::Down),
+            (KeyCode::Left,  FocusDirection::Left),
+            (KeyCode::Right, FocusDirection::Right),
+        ];
+
+    for (key, direction) in directions
+    {
+        if !keys.just_pressed(key) { continue; }
+        commands.add(MoveFocus::<U, C>::new(direction));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Move focus with the d-pad of any gamepad (directional navigation).
+pub fn gamepad_focus_navigation<U: LunexUi, C: LunexCursor>(
+    mut commands : Commands,
+    buttons      : Res<Input<GamepadButton>>,
+){
+    for button in buttons.get_just_pressed()
+    {
+        let direction = match button.button_type
+        {
+            GamepadButtonType::DPadUp    => FocusDirection::Up,
+            GamepadButtonType::DPadDown  => FocusDirection::Down,
+            GamepadButtonType::DPadLeft  => FocusDirection::Left,
+            GamepadButtonType::DPadRight => FocusDirection::Right,
+            _ => continue,
+        };
+        commands.add(MoveFocus::<U, C>::new(direction));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
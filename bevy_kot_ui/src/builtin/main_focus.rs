@@ -0,0 +1,59 @@
+//local shortcuts
+use crate::*;
+use crate::builtin::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy_fn_plugin::bevy_plugin;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Use to tag the main focus cursor (it follows the [`Focused`] element).
+/// - Spawn an entity with this component to enable keyboard/gamepad interactions. The entity must not have a
+///   `bevy_lunex::Cursor`.
+#[derive(Component, Default, Copy, Clone, Debug)]
+pub struct MainFocusCursor;
+impl PlainMouseCursor for MainFocusCursor {}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Keyboard interaction source (`Enter`/`Space`).
+/// - Uses the main focus cursor to target the main UI in a given OS window.
+/// - Shares the main focus cursor with [`GamepadConfirmMain`], so elements should only be built for one of them (see
+///   [`FocusNavigationPlugin`]).
+pub type KeyboardConfirmMain = KeyboardConfirm<MainUi, MainFocusCursor>;
+
+/// Gamepad interaction source (`South`/`A` button).
+/// - Uses the main focus cursor to target the main UI in a given OS window.
+/// - Shares the main focus cursor with [`KeyboardConfirmMain`], so elements should only be built for one of them (see
+///   [`FocusNavigationPlugin`]).
+pub type GamepadConfirmMain = GamepadConfirm<MainUi, MainFocusCursor>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Make the main UI usable with a keyboard or gamepad.
+/// - Registers [`MainFocusCursor`] as a focus cursor, and registers [`KeyboardConfirmMain`] and
+///   [`GamepadConfirmMain`] as interaction sources.
+/// - Adds keyboard (`Tab`/arrow keys) and gamepad (d-pad) focus navigation.
+/// - Elements must be built with the `KeyboardConfirmMain` or `GamepadConfirmMain` source to be focusable.
+/// - Build each element for only one of the two sources. Both sources use the same cursor, so an element built for
+///   both sources will be hovered by both sources and its hover callbacks will be invoked twice per tick.
+#[bevy_plugin]
+pub fn FocusNavigationPlugin(app: &mut App)
+{
+    app.register_focus_cursor::<MainUi, MainFocusCursor>()
+        .register_interaction_source(KeyboardConfirmMain::default())
+        .register_interaction_source(GamepadConfirmMain::default())
+        .add_systems(First,
+            (
+                keyboard_focus_navigation::<MainUi, MainFocusCursor>,
+                gamepad_focus_navigation::<MainUi, MainFocusCursor>,
+            )
+                .before(FocusCursorSet)
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
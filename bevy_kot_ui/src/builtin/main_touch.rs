@@ -0,0 +1,41 @@
+//local shortcuts
+use crate::*;
+use crate::builtin::*;
+
+//third-party shortcuts
+use bevy_fn_plugin::bevy_plugin;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Touch interaction source for touch slot `SLOT`.
+/// - Uses the slot's touch cursor to target the main UI in the primary window.
+pub type TouchMain<const SLOT: usize> = TouchSource<MainUi, SLOT>;
+
+/// Element that may be interacted with by all touch slots registered by [`MainTouchPlugin`].
+/// - Insert this bundle on elements built with a [`TouchMain`] source.
+pub type TouchInteractiveElementMain = (
+    InteractiveElement<TouchMain<0>>,
+    InteractiveElement<TouchMain<1>>,
+    InteractiveElement<TouchMain<2>>,
+    InteractiveElement<TouchMain<3>>,
+    InteractiveElement<TouchMain<4>>,
+);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Make the main UI usable with up to five simultaneous touches.
+/// - Registers [`TouchMain`] sources for touch slots `0..5`.
+#[bevy_plugin]
+pub fn MainTouchPlugin(app: &mut App)
+{
+    app.register_touch_source::<MainUi, 0>()
+        .register_touch_source::<MainUi, 1>()
+        .register_touch_source::<MainUi, 2>()
+        .register_touch_source::<MainUi, 3>()
+        .register_touch_source::<MainUi, 4>();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
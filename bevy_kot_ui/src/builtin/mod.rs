@@ -1,13 +1,21 @@
 //module tree
 mod debug_overlay;
+mod keyboard_gamepad;
+mod main_focus;
 mod main_mouses;
+mod main_touch;
 mod main_ui;
 mod mouse_buttons;
 mod plain_mouse_cursor;
+mod touch;
 
 //API exports
 pub use crate::builtin::debug_overlay::*;
+pub use crate::builtin::keyboard_gamepad::*;
+pub use crate::builtin::main_focus::*;
 pub use crate::builtin::main_mouses::*;
+pub use crate::builtin::main_touch::*;
 pub use crate::builtin::main_ui::*;
 pub use crate::builtin::mouse_buttons::*;
 pub use crate::builtin::plain_mouse_cursor::*;
+pub use crate::builtin::touch::*;
@@ -0,0 +1,244 @@
+//local shortcuts
+use crate::*;
+use crate::builtin::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParamItem;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::input::touch::Touch;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+//standard shortcuts
+use std::marker::PhantomData;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+type TouchSourceParam = (SRes<Touches>, SRes<TouchSlots>);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Get the touch assigned to a slot (including touches released since the last input update).
+fn slot_touch<'a>(touches: &'a Touches, slots: &TouchSlots, slot: usize) -> Option<&'a Touch>
+{
+    let touch_id = slots.touch_id(slot)?;
+    touches.get_pressed(touch_id).or_else(|| touches.get_released(touch_id))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Assign new touches to free slots, and free the slots of touches that ended.
+fn update_touch_slots(touches: Res<Touches>, mut slots: ResMut<TouchSlots>)
+{
+    // free slots
+    // - released touches keep their slot until the release has been seen by interaction pipelines
+    // - canceled touches are freed immediately so their cursors are lost and presses are aborted
+    for slot in slots.slots.iter_mut()
+    {
+        let Some(touch_id) = *slot else { continue; };
+        if touches.get_pressed(touch_id).is_some() || touches.just_released(touch_id) { continue; }
+        *slot = None;
+    }
+
+    // assign new touches
+    let mut new_touches: Vec<u64> = touches.iter_just_pressed().map(|touch| touch.id()).collect();
+    new_touches.sort_unstable();
+
+    for touch_id in new_touches
+    {
+        if slots.slot(touch_id).is_some() { continue; }
+        let Some(slot) = slots.slots.iter_mut().find(|slot| slot.is_none())
+        else
+        {
+            tracing::debug!("no free touch slot for touch {touch_id}");
+            break;
+        };
+        *slot = Some(touch_id);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Place touch cursors for a slot at the slot's touch point.
+/// - Cursors of empty slots have no [`CursorLocation`], so the cursor is unavailable to interaction pipelines.
+fn update_touch_cursor<const SLOT: usize>(
+    mut commands : Commands,
+    touches      : Res<Touches>,
+    slots        : Res<TouchSlots>,
+    windows      : Query<(Entity, &Window, Has<PrimaryWindow>)>,
+    cameras      : Query<(&Camera, &GlobalTransform)>,
+    mut cursors  : Query<(Entity, Option<&mut CursorLocation>), With<TouchCursor<SLOT>>>,
+){
+    // touches are reported for the primary window
+    let location = slot_touch(&touches, &slots, SLOT)
+        .and_then(|touch|
+            {
+                let (window_entity, window, is_primary) = windows
+                    .iter()
+                    .find(|(_, _, is_primary)| *is_primary)
+                    .or_else(|| windows.iter().next())?;
+                let screen = touch.position();
+                let world = screen_to_world(window_entity, window, is_primary, screen, &cameras);
+                Some(CursorLocation::new(Some(window_entity), screen, world))
+            }
+        );
+
+    for (entity, cursor_location) in cursors.iter_mut()
+    {
+        match (location, cursor_location)
+        {
+            (Some(location), Some(mut cursor_location)) =>
+            {
+                if *cursor_location != location { *cursor_location = location; }
+            }
+            (Some(location), None) => { commands.entity(entity).insert(location); }
+            (None, Some(_))        => { commands.entity(entity).remove::<CursorLocation>(); }
+            (None, None)           => (),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Assignments of active touches to touch slots.
+/// - Each slot has a [`TouchCursor`] and may have [`TouchSource`]s (see [`TouchAppExt::register_touch_source()`]).
+/// - New touches are assigned to the lowest free slot. Touches that begin while all slots are taken are ignored.
+#[derive(Resource, Default, Debug)]
+pub struct TouchSlots
+{
+    slots: Vec<Option<u64>>,
+}
+
+impl TouchSlots
+{
+    /// Get the id of the touch assigned to a slot.
+    pub fn touch_id(&self, slot: usize) -> Option<u64>
+    {
+        self.slots.get(slot).copied().flatten()
+    }
+
+    /// Get the slot a touch is assigned to.
+    pub fn slot(&self, touch_id: u64) -> Option<usize>
+    {
+        self.slots.iter().position(|slot| *slot == Some(touch_id))
+    }
+
+    /// Get the number of slots.
+    pub fn len(&self) -> usize
+    {
+        self.slots.len()
+    }
+
+    /// Check if there are no slots (no touch sources have been registered).
+    pub fn is_empty(&self) -> bool
+    {
+        self.slots.is_empty()
+    }
+
+    fn reserve(&mut self, slot: usize)
+    {
+        if self.slots.len() <= slot { self.slots.resize(slot + 1, None); }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Cursor that follows the touch assigned to touch slot `SLOT`.
+/// - Touch cursor entities are spawned by [`TouchAppExt::register_touch_source()`].
+#[derive(Component, Default, Copy, Clone, Debug)]
+pub struct TouchCursor<const SLOT: usize>;
+impl<const SLOT: usize> PlainMouseCursor for TouchCursor<SLOT> {}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Touch interaction source for the touch assigned to touch slot `SLOT`.
+/// - The touch acts as a click from when it starts until it ends, and hovers elements under the touch point while
+///   active. Canceled touches abort presses.
+/// - Element states like `Pressed` and `Hovered` are shared by all sources, so elements should only be interacted with
+///   by one touch at a time.
+#[derive(Resource, Debug)]
+pub struct TouchSource<U: LunexUi, const SLOT: usize>
+{
+    _phantom: PhantomData<U>,
+}
+
+impl<U: LunexUi, const SLOT: usize> Default for TouchSource<U, SLOT>
+{ fn default() -> Self { Self{ _phantom: PhantomData } } }
+
+impl<U: LunexUi, const SLOT: usize> InteractionSource for TouchSource<U, SLOT>
+{
+    type SourceParam = TouchSourceParam;
+    type LunexUi     = U;
+    type LunexCursor = TouchCursor<SLOT>;
+
+    fn just_clicked(&self, (touches, slots): &SystemParamItem<TouchSourceParam>) -> bool
+    {
+        slots.touch_id(SLOT).is_some_and(|touch_id| touches.just_pressed(touch_id))
+    }
+    fn is_clicked(&self, (touches, slots): &SystemParamItem<TouchSourceParam>) -> bool
+    {
+        slots.touch_id(SLOT).is_some_and(|touch_id| touches.get_pressed(touch_id).is_some())
+    }
+    fn just_unclicked(&self, (touches, slots): &SystemParamItem<TouchSourceParam>) -> bool
+    {
+        slots.touch_id(SLOT).is_some_and(|touch_id| touches.just_released(touch_id))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set that contains touch slot and touch cursor updates. Runs in schedule `First` before
+/// [`InteractionSourceSet`].
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct TouchCursorSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub trait TouchAppExt
+{ fn register_touch_source<U: LunexUi, const SLOT: usize>(&mut self) -> &mut Self; }
+
+impl TouchAppExt for App
+{
+    /// Register a [`TouchSource`] for touch slot `SLOT`.
+    /// - Adds the [`TouchSlots`] resource and spawns a [`TouchCursor`] entity for the slot if necessary.
+    /// - Register one source per slot for each number of simultaneous touches you want to support (e.g. slots `0..5`
+    ///   for five touches).
+    /// - Requires the `Touches` resource from `bevy::input::InputPlugin`.
+    fn register_touch_source<U: LunexUi, const SLOT: usize>(&mut self) -> &mut Self
+    {
+        if !self.world.contains_resource::<TouchSlots>()
+        {
+            self.init_resource::<TouchSlots>()
+                .configure_sets(First, TouchCursorSet.before(InteractionSourceSet))
+                .add_systems(First, update_touch_slots.in_set(TouchCursorSet));
+        }
+        self.world.resource_mut::<TouchSlots>().reserve(SLOT);
+
+        let has_cursor = self.world
+            .query_filtered::<(), With<TouchCursor<SLOT>>>()
+            .iter(&self.world)
+            .next()
+            .is_some();
+        if !has_cursor
+        {
+            // apply cursor location changes before interaction pipelines run so new touches can click
+            self.world.spawn(TouchCursor::<SLOT>);
+            self.add_systems(First,
+                (
+                    update_touch_cursor::<SLOT>,
+                    apply_deferred,
+                )
+                    .chain()
+                    .after(update_touch_slots)
+                    .in_set(TouchCursorSet)
+            );
+        }
+
+        self.register_interaction_source(TouchSource::<U, SLOT>::default())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
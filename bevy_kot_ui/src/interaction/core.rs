@@ -9,7 +9,8 @@ use bevy_lunex::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Components with this trait are used to tag `bevy_lunex::UiTree<Ui>`s for accurate filtering in interaction pipelines.
-/// - Currently only one UI per OS window may have a given tag.
+/// - Multiple UIs may have the same tag, including in the same OS window (see [`UiTreeWindow`](crate::UiTreeWindow),
+///   [`UiTreeLayer`](crate::UiTreeLayer), and [`InUiTree`](crate::InUiTree)).
 pub trait LunexUi: Component + Default {}
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -76,6 +77,52 @@ pub trait LunexCursor: Component
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Policy for cleaning up element states when an [`InteractionSource`] loses track of the user.
+///
+/// Used by the built-in interaction pipeline. Pressed elements are cleaned up by invoking `Callback<AbortPress>`, and
+/// hovered elements by invoking `Callback<OnUnHover>`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct InteractionCleanupPolicy
+{
+    /// Abort presses when the source's cursor is disabled or despawned.
+    pub abort_press_on_cursor_lost: bool,
+    /// Unhover elements when the source's cursor is disabled or despawned.
+    pub unhover_on_cursor_lost: bool,
+    /// Abort presses when the focused window changes (e.g. when alt-tabbing out of the app).
+    pub abort_press_on_focus_change: bool,
+    /// Unhover elements when the focused window changes.
+    pub unhover_on_focus_change: bool,
+}
+
+impl InteractionCleanupPolicy
+{
+    /// Policy that never cleans up element states.
+    pub fn none() -> Self
+    {
+        Self{
+            abort_press_on_cursor_lost  : false,
+            unhover_on_cursor_lost      : false,
+            abort_press_on_focus_change : false,
+            unhover_on_focus_change     : false,
+        }
+    }
+}
+
+impl Default for InteractionCleanupPolicy
+{
+    fn default() -> Self
+    {
+        Self{
+            abort_press_on_cursor_lost  : true,
+            unhover_on_cursor_lost      : true,
+            abort_press_on_focus_change : true,
+            unhover_on_focus_change     : true,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// An interaction source represents a source of interactions (hovers and clicks) for `bevy_lunex::UiTree<Ui>s` with a
 /// specific `LunexUi` tag.
 ///
@@ -93,6 +140,9 @@ pub trait InteractionSource: Resource
     fn just_clicked(&self, source: &SystemParamItem<Self::SourceParam>) -> bool;
     fn is_clicked(&self, source: &SystemParamItem<Self::SourceParam>) -> bool;
     fn just_unclicked(&self, source: &SystemParamItem<Self::SourceParam>) -> bool;
+
+    /// Get the policy for cleaning up element states when the source loses its cursor or window focus.
+    fn cleanup_policy(&self) -> InteractionCleanupPolicy { InteractionCleanupPolicy::default() }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -4,6 +4,8 @@ use crate::*;
 //third-party shortcuts
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::window::{PrimaryWindow, WindowRef};
 use bevy_lunex::prelude::*;
 
 //standard shortcuts
@@ -11,40 +13,196 @@ use bevy_lunex::prelude::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Helper system param for accessing a cursor's world position.
+/// Location of a cursor entity, updated by [`update_cursor_locations()`] for entities with a `bevy_lunex::Cursor`.
+/// - The default location is outside all windows (see [`CursorLocation::is_outside()`]).
+#[derive(Component, Copy, Clone, PartialEq, Debug)]
+pub struct CursorLocation
+{
+    window  : Option<Entity>,
+    screen  : Vec2,
+    world   : Vec2,
+    outside : bool,
+}
+
+impl CursorLocation
+{
+    /// Make a new cursor location.
+    pub fn new(window: Option<Entity>, screen: Vec2, world: Vec2) -> Self
+    {
+        Self{ window, screen, world, outside: false }
+    }
+
+    /// Make a cursor location for a cursor that is outside all windows.
+    pub fn outside() -> Self
+    {
+        // mirror `bevy_lunex::Cursor`'s location when the cursor is outside all windows
+        Self{ window: None, screen: Vec2::splat(-10000.0), world: Vec2::splat(-10000.0), outside: true }
+    }
+
+    /// Check if the cursor is outside all windows. The cursor's positions are meaningless if this is true.
+    pub fn is_outside(&self) -> bool
+    {
+        self.outside
+    }
+
+    /// Get the window the cursor is in.
+    pub fn window(&self) -> Option<Entity>
+    {
+        self.window
+    }
+
+    /// Get the cursor's screen position in its window (origin is top left).
+    pub fn screen(&self) -> Vec2
+    {
+        self.screen
+    }
+
+    /// Get the cursor's world position in its window (origin is center of screen, offset by the window's camera).
+    pub fn world(&self) -> Vec2
+    {
+        self.world
+    }
+}
+
+impl Default for CursorLocation
+{
+    fn default() -> Self { Self::outside() }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Convert a screen position in a window to a world position, using the camera that renders to the window (if there
+/// is one).
+pub(crate) fn screen_to_world(
+    window_entity : Entity,
+    window        : &Window,
+    is_primary    : bool,
+    screen        : Vec2,
+    cameras       : &Query<(&Camera, &GlobalTransform)>,
+) -> Vec2
+{
+    // get the camera that renders to this window
+    let camera_translation = cameras
+        .iter()
+        .find(|(camera, _)|
+            match &camera.target
+            {
+                RenderTarget::Window(WindowRef::Primary)       => is_primary,
+                RenderTarget::Window(WindowRef::Entity(entity)) => *entity == window_entity,
+                _ => false,
+            }
+        )
+        .map(|(_, transform)| transform.translation().truncate())
+        .unwrap_or_default();
+
+    Vec2::new(
+            camera_translation.x + screen.x - window.resolution.width() / 2.0,
+            camera_translation.y + window.resolution.height() / 2.0 - screen.y,
+        )
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Update [`CursorLocation`]s of entities with a `bevy_lunex::Cursor`.
+///
+/// The cursor is placed in the focused window if the cursor is there, otherwise in whichever window contains it.
+/// World positions are computed with the camera that renders to the cursor's window (if there is one).
+pub fn update_cursor_locations(
+    mut commands : Commands,
+    windows      : Query<(Entity, &Window, Has<PrimaryWindow>)>,
+    cameras      : Query<(&Camera, &GlobalTransform)>,
+    mut cursors  : Query<(Entity, Option<&mut CursorLocation>), With<Cursor>>,
+){
+    // find the window containing the cursor
+    let mut location = CursorLocation::outside();
+    let mut found_focused = false;
+
+    for (window_entity, window, is_primary) in windows.iter()
+    {
+        let Some(screen) = window.cursor_position() else { continue; };
+        if found_focused || (location.window.is_some() && !window.focused) { continue; }
+        found_focused = window.focused;
+
+        let world = screen_to_world(window_entity, window, is_primary, screen, &cameras);
+        location = CursorLocation::new(Some(window_entity), screen, world);
+    }
+
+    // update the cursors
+    for (entity, cursor_location) in cursors.iter_mut()
+    {
+        match cursor_location
+        {
+            Some(mut cursor_location) =>
+            {
+                if *cursor_location != location { *cursor_location = location; }
+            }
+            None => { commands.entity(entity).insert(location); }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Helper system param for accessing a cursor's position.
 #[derive(SystemParam)]
 pub struct CursorPos<'w, 's, C: LunexCursor>
 {
-    cursor: Query<'w, 's, &'static Cursor, (With<C>, Without<Disabled>)>,  //todo: Option<InFocusedWindow>
+    cursor: Query<'w, 's, &'static CursorLocation, (With<C>, Without<Disabled>)>,
 }
 
 impl<'w, 's, C: LunexCursor> CursorPos<'w, 's, C>
 {
-    /// Check if a cursor is available in the focused window.
+    /// Check if a cursor is available.
     pub fn available(&self) -> bool
     {
         !self.cursor.is_empty()
     }
 
-    /// Get the cursor's world position in the focused window (origin is center of screen).
+    /// Get the cursor's location.
+    ///
+    /// Returns `None` if the cursor doesn't exist or is disabled.
+    pub fn location(&self) -> Option<CursorLocation>
+    {
+        self.cursor.get_single().ok().copied()
+    }
+
+    /// Get the window the cursor is in.
+    ///
+    /// Returns `None` if the cursor doesn't exist, is disabled, or is outside all windows.
+    pub fn window(&self) -> Option<Entity>
+    {
+        self.cursor.get_single().ok()?.window()
+    }
+
+    /// Get the cursor's world position in its window (origin is center of screen).
     ///
     /// Returns `None` if the cursor doesn't exist or is disabled.
     pub fn get_world(&self) -> Option<Vec2>
     {
-        let Ok(cursor) = self.cursor.get_single() else { return None; };
-        Some(*cursor.location_world())
+        Some(self.cursor.get_single().ok()?.world())
     }
 
-    /// Get the cursor's screen position in the focused window (origin is top left).
+    /// Get the cursor's screen position in its window (origin is top left).
     ///
     /// Returns `None` if the cursor doesn't exist or is disabled.
     pub fn get_screen(&self) -> Option<Vec2>
     {
-        let Ok(cursor) = self.cursor.get_single() else { return None; };
-        Some(*cursor.location_screen())
+        Some(self.cursor.get_single().ok()?.screen())
     }
 
-    //todo: request position for specific window
+    /// Get the cursor's world position if it is in a specific window.
+    pub fn get_world_in(&self, window: Entity) -> Option<Vec2>
+    {
+        if self.window() != Some(window) { return None; }
+        self.get_world()
+    }
+
+    /// Get the cursor's screen position if it is in a specific window.
+    pub fn get_screen_in(&self, window: Entity) -> Option<Vec2>
+    {
+        if self.window() != Some(window) { return None; }
+        self.get_screen()
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,343 @@
+//local shortcuts
+use crate::*;
+
+//third-party shortcuts
+use bevy::ecs::system::{StaticSystemParam, SystemParam};
+use bevy::prelude::*;
+use bevy_lunex::prelude::*;
+
+//standard shortcuts
+use std::any::Any;
+use std::sync::Arc;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+type DraggedElement = (&'static Widget, Option<&'static InUiTree>, Option<&'static DragPayload>);
+type DropTargetInfo = (Entity, &'static Widget, &'static DropTarget, Option<&'static InUiTree>);
+type DropFilter = Arc<dyn Fn(Option<&DragPayload>) -> bool + Send + Sync + 'static>;
+
+/// Interaction barriers that block drops for the source `S`.
+#[derive(SystemParam)]
+pub(crate) struct DropBarriers<'w, 's, S: InteractionSource>
+{
+    barrier_param   : StaticSystemParam<'w, 's, <SourceCursor<S> as LunexCursor>::BarrierParam>,
+    barrier_widgets : Query<'w, 's, TargetWidget, SourceBarrierFilter<S>>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Get the cursor's location. Positions in UI tree coordinates are `location.world().invert_y()`.
+///
+/// Returns `None` if the cursor is unavailable or outside all windows.
+fn cursor_location<C: LunexCursor>(cursor_pos: &CursorPos<C>) -> Option<CursorLocation>
+{
+    let location = cursor_pos.location()?;
+    if location.is_outside() { return None; }
+    Some(location)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Find the top-most visible drop target under the cursor that accepts the payload.
+/// - Trees that can be seen from the cursor's window are searched from top-most to bottom-most.
+/// - Drop targets below an interaction barrier under the cursor are ignored, and the barrier's tree occludes the trees
+///   below it (the same as for interaction targeting).
+fn find_drop_target<S: InteractionSource>(
+    uis      : &UiTrees<S::LunexUi>,
+    location : CursorLocation,
+    element  : Entity,
+    payload  : Option<&DragPayload>,
+    targets  : &Query<DropTargetInfo, Without<Disabled>>,
+    barriers : &DropBarriers<S>,
+) -> Option<Entity>
+{
+    let position = location.world().invert_y();
+
+    for (tree, ui) in uis.in_window(location.window())
+    {
+        let depth_limit = find_barrier_depth::<S>(
+                tree,
+                ui,
+                location.screen(),
+                location.world(),
+                &barriers.barrier_widgets,
+                &barriers.barrier_param,
+            );
+        let mut top: Option<(f32, Entity)> = None;
+
+        for (entity, widget, drop_target, in_tree) in targets.iter()
+        {
+            if entity == element { continue; }
+            if !belongs_to_tree(in_tree, tree) { continue; }
+            let Ok(branch) = widget.fetch(ui) else { continue; };
+            if !branch.is_visible() { continue; }
+            if widget_relative_position(ui, widget, position).is_none() { continue; }
+            if !drop_target.accepts(payload) { continue; }
+
+            let depth = branch.get_depth();
+            if depth_limit.is_some_and(|limit| limit > depth) { continue; }
+            if top.is_some_and(|(top_depth, _)| top_depth >= depth) { continue; }
+            top = Some((depth, entity));
+        }
+
+        if let Some((_, entity)) = top { return Some(entity); }
+        if depth_limit.is_some() { return None; }
+    }
+
+    None
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Start dragging an element. Inserts [`Dragging`] on the element.
+pub(crate) fn start_drag<S: InteractionSource>(
+    In(element)  : In<Entity>,
+    mut commands : Commands,
+    uis          : UiTrees<S::LunexUi>,
+    cursor_pos   : CursorPos<S::LunexCursor>,
+    elements     : Query<DraggedElement>,
+    targets      : Query<DropTargetInfo, Without<Disabled>>,
+    barriers     : DropBarriers<S>,
+) -> Option<DragInfo>
+{
+    let (widget, in_tree, payload) = elements.get(element).ok()?;
+    let (tree, ui) = uis.find(in_tree, widget)?;
+    let location = cursor_location(&cursor_pos)?;
+    let position = location.world().invert_y();
+
+    let info = DragInfo{
+            element,
+            tree,
+            start       : position,
+            position,
+            grab        : widget_relative_position_unbounded(ui, widget, position)?,
+            drop_target : find_drop_target(&uis, location, element, payload, &targets, &barriers),
+        };
+    commands.entity(element).insert(Dragging{ info });
+
+    Some(info)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Update the drag state of an element that is being dragged.
+/// - If the cursor is unavailable, the previous cursor position is retained.
+pub(crate) fn update_drag<S: InteractionSource>(
+    In(element) : In<Entity>,
+    uis         : UiTrees<S::LunexUi>,
+    cursor_pos  : CursorPos<S::LunexCursor>,
+    mut dragged : Query<(&mut Dragging, Option<&DragPayload>)>,
+    targets     : Query<DropTargetInfo, Without<Disabled>>,
+    barriers    : DropBarriers<S>,
+) -> Option<DragInfo>
+{
+    let (mut dragging, payload) = dragged.get_mut(element).ok()?;
+    let mut info = dragging.info;
+
+    if let Some(location) = cursor_location(&cursor_pos)
+    {
+        info.position    = location.world().invert_y();
+        info.drop_target = find_drop_target(&uis, location, element, payload, &targets, &barriers);
+    }
+    if dragging.info != info { dragging.info = info; }
+
+    Some(info)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stop dragging an element. Removes [`Dragging`] from the element.
+/// - If `drop` is false then the returned drop target will be `None`.
+pub(crate) fn end_drag(
+    In((element, drop)) : In<(Entity, bool)>,
+    mut commands        : Commands,
+    dragged             : Query<&Dragging>,
+) -> Option<DragInfo>
+{
+    let mut info = dragged.get(element).ok()?.info;
+    if !drop { info.drop_target = None; }
+    commands.entity(element).remove::<Dragging>();
+
+    Some(info)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Get the position of a point relative to a widget's current area.
+/// - Returns `(0, 0)` at the widget's top left corner and `(1, 1)` at its bottom right corner.
+/// - Returns `None` if the widget can't be found or has zero area.
+///
+/// Relative positions are unaffected by window resizing, so they can be cached between ticks.
+pub fn widget_relative_position_unbounded<U: LunexUi>(ui: &UiTree<U>, widget: &Widget, point: Vec2) -> Option<Vec2>
+{
+    let branch = widget.fetch(ui).ok()?;
+    let position = branch.get_container().get_position();
+    let size = position.point_2 - position.point_1;
+    if size.x == 0.0 || size.y == 0.0 { return None; }
+    Some((point - position.point_1) / size)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Get the position of a point relative to a widget's current area if the point is inside the widget.
+/// - See [`widget_relative_position_unbounded()`].
+pub fn widget_relative_position<U: LunexUi>(ui: &UiTree<U>, widget: &Widget, point: Vec2) -> Option<Vec2>
+{
+    let relative = widget_relative_position_unbounded(ui, widget, point)?;
+    if relative.cmplt(Vec2::ZERO).any() || relative.cmpgt(Vec2::ONE).any() { return None; }
+    Some(relative)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Information about a drag of an interactive element.
+///
+/// Positions are in UI tree coordinates (the same coordinates as widget areas), and are refreshed from the cursor every
+/// tick. Use [`DragInfo::position_in()`] to get the cursor position relative to a widget's current area.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DragInfo
+{
+    element     : Entity,
+    tree        : Entity,
+    start       : Vec2,
+    position    : Vec2,
+    grab        : Vec2,
+    drop_target : Option<Entity>,
+}
+
+impl DragInfo
+{
+    /// Get the element being dragged.
+    pub fn element(&self) -> Entity
+    {
+        self.element
+    }
+
+    /// Get the UI tree that contains the dragged element.
+    pub fn tree(&self) -> Entity
+    {
+        self.tree
+    }
+
+    /// Get the cursor position when the drag started.
+    /// - This will be stale if the window was resized during the drag.
+    pub fn start(&self) -> Vec2
+    {
+        self.start
+    }
+
+    /// Get the current cursor position.
+    pub fn position(&self) -> Vec2
+    {
+        self.position
+    }
+
+    /// Get where the element was grabbed, relative to the element's widget (see [`widget_relative_position()`]).
+    pub fn grab(&self) -> Vec2
+    {
+        self.grab
+    }
+
+    /// Get the drop target under the cursor.
+    /// - In `on_drag_end` callbacks, this is the target that received the drop (`None` if the drag was aborted).
+    pub fn drop_target(&self) -> Option<Entity>
+    {
+        self.drop_target
+    }
+
+    /// Get the current cursor position relative to a widget (see [`widget_relative_position_unbounded()`]).
+    pub fn position_in<U: LunexUi>(&self, ui: &UiTree<U>, widget: &Widget) -> Option<Vec2>
+    {
+        widget_relative_position_unbounded(ui, widget, self.position)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component added to interactive elements while they are being dragged.
+#[derive(Component, Copy, Clone, PartialEq, Debug)]
+pub struct Dragging
+{
+    info: DragInfo,
+}
+
+impl Dragging
+{
+    /// Get the current drag information.
+    pub fn info(&self) -> &DragInfo
+    {
+        &self.info
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Payload carried by a draggable element. Drop targets use payloads to decide if they accept a drop.
+#[derive(Component, Clone)]
+pub struct DragPayload(Arc<dyn Any + Send + Sync>);
+
+impl DragPayload
+{
+    pub fn new<P: Send + Sync + 'static>(payload: P) -> Self
+    {
+        Self(Arc::new(payload))
+    }
+
+    /// Get the payload if it has type `P`.
+    pub fn get<P: Send + Sync + 'static>(&self) -> Option<&P>
+    {
+        self.0.downcast_ref::<P>()
+    }
+
+    /// Check if the payload has type `P`.
+    pub fn is<P: Send + Sync + 'static>(&self) -> bool
+    {
+        self.0.is::<P>()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component for widgets that can receive drops from draggable interactive elements.
+/// - The drop target is the top-most visible accepting target under the cursor when a drag ends with an unpress.
+///   Drops are received by the `CallbackWith<OnDrop, DragInfo>` callback on the target (if it has one).
+/// - Targets behind an interaction barrier under the cursor (in the same or a higher UI tree) can't receive drops.
+/// - Add `Disabled` to the entity to stop receiving drops.
+#[derive(Component, Clone)]
+pub struct DropTarget
+{
+    filter: DropFilter,
+}
+
+impl DropTarget
+{
+    /// Accept all drops, including drags without a payload.
+    pub fn any() -> Self
+    {
+        Self{ filter: Arc::new(|_| true) }
+    }
+
+    /// Accept drops with a payload of type `P`.
+    pub fn accepting<P: Send + Sync + 'static>() -> Self
+    {
+        Self{ filter: Arc::new(|payload| payload.is_some_and(DragPayload::is::<P>)) }
+    }
+
+    /// Accept drops with a payload of type `P` that passes the filter.
+    pub fn accepting_if<P: Send + Sync + 'static>(filter: impl Fn(&P) -> bool + Send + Sync + 'static) -> Self
+    {
+        Self{ filter: Arc::new(move |payload| payload.and_then(DragPayload::get::<P>).is_some_and(&filter)) }
+    }
+
+    /// Check if a drop with the given payload is accepted.
+    pub fn accepts(&self, payload: Option<&DragPayload>) -> bool
+    {
+        (self.filter)(payload)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,319 @@
+//local shortcuts
+use crate::*;
+use bevy_kot_ecs::*;
+
+//third-party shortcuts
+use bevy::ecs::system::Command;
+use bevy::prelude::*;
+use bevy_lunex::prelude::*;
+
+//standard shortcuts
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+type FocusCandidateInfo = (Entity, &'static Widget, Option<&'static InUiTree>, Option<&'static FocusOrder>);
+type FocusTargeterFilter<U, C> = (With<ElementInteractionTargeter<U, C>>, Without<Disabled>);
+type FocusedWidget = (&'static Widget, Option<&'static InUiTree>);
+type FocusCursorLocation = (Entity, Option<&'static mut CursorLocation>);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone, Debug)]
+struct FocusCandidate
+{
+    entity    : Entity,
+    tree      : Entity,
+    tree_rank : usize,
+    order     : i32,
+    center    : Vec2,
+}
+
+impl FocusCandidate
+{
+    /// Tab order: top-most trees first, then by [`FocusOrder`], then top-to-bottom and left-to-right.
+    fn tab_cmp(&self, other: &Self) -> Ordering
+    {
+        self.tree_rank.cmp(&other.tree_rank)
+            .then(self.order.cmp(&other.order))
+            .then(self.center.y.total_cmp(&other.center.y))
+            .then(self.center.x.total_cmp(&other.center.x))
+            .then(self.entity.cmp(&other.entity))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Get the center of a visible widget in UI tree coordinates (origin is top left).
+fn visible_widget_center<U: LunexUi>(ui: &UiTree<U>, widget: &Widget) -> Option<Vec2>
+{
+    let branch = widget.fetch(ui).ok()?;
+    if !branch.is_visible() { return None; }
+    let position = branch.get_container().get_position();
+    Some((position.point_1 + position.point_2) / 2.0)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Score a candidate for directional navigation (lower is better).
+///
+/// Returns `None` if the candidate is not in the requested direction.
+fn directional_score(direction: FocusDirection, from: Vec2, to: Vec2) -> Option<f32>
+{
+    // ui tree coordinates point down, so 'up' is negative y
+    let delta = to - from;
+    let (along, across) = match direction
+    {
+        FocusDirection::Up    => (-delta.y, delta.x.abs()),
+        FocusDirection::Down  => (delta.y, delta.x.abs()),
+        FocusDirection::Left  => (-delta.x, delta.y.abs()),
+        FocusDirection::Right => (delta.x, delta.y.abs()),
+        FocusDirection::Next | FocusDirection::Previous => return None,
+    };
+    if along <= 0.0 { return None; }
+
+    // prefer candidates that are aligned with the current element
+    Some(along + 2.0 * across)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn move_focus<U: LunexUi, C: LunexCursor>(
+    In(direction) : In<FocusDirection>,
+    mut commands  : Commands,
+    uis           : UiTrees<U>,
+    windows       : Query<(Entity, &Window)>,
+    focused       : Query<Entity, With<Focused>>,
+    elements      : Query<FocusCandidateInfo, FocusTargeterFilter<U, C>>,
+) -> Option<Entity>
+{
+    // find the currently focused element
+    let current = focused
+        .iter()
+        .find_map(|entity|
+            {
+                let (_, widget, in_tree, _) = elements.get(entity).ok()?;
+                let (tree, ui) = uis.find(in_tree, widget)?;
+                Some((entity, tree, visible_widget_center(ui, widget)?))
+            }
+        );
+
+    // navigate within the window of the current element, or the focused window if nothing is focused
+    let window = match current
+    {
+        Some((_, tree, _)) => uis.window(tree),
+        None => windows.iter().find(|(_, window)| window.focused).map(|(entity, _)| entity),
+    };
+
+    // collect candidates (elements are associated with the top-most tree that displays them)
+    let trees = uis.in_window(window);
+    let mut candidates: Vec<FocusCandidate> = elements
+        .iter()
+        .filter_map(|(entity, widget, in_tree, order)|
+            {
+                trees
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (tree, _))| belongs_to_tree(in_tree, *tree))
+                    .find_map(|(tree_rank, (tree, ui))|
+                        {
+                            Some(FocusCandidate{
+                                    entity,
+                                    tree: *tree,
+                                    tree_rank,
+                                    order  : order.map(|o| o.0).unwrap_or_default(),
+                                    center : visible_widget_center(ui, widget)?,
+                                })
+                        }
+                    )
+            }
+        )
+        .collect();
+    if candidates.is_empty() { return None; }
+    candidates.sort_unstable_by(FocusCandidate::tab_cmp);
+
+    // select the next element
+    let current = current.and_then(|(entity, tree, center)|
+            Some((entity, tree, center, candidates.iter().position(|c| c.entity == entity)?))
+        );
+    let next = match current
+    {
+        None => match direction
+        {
+            FocusDirection::Previous => candidates.last(),
+            _                        => candidates.first(),
+        },
+        Some((entity, tree, center, index)) => match direction
+        {
+            FocusDirection::Next     => candidates.get((index + 1) % candidates.len()),
+            FocusDirection::Previous => candidates.get((index + candidates.len() - 1) % candidates.len()),
+            _ => candidates
+                .iter()
+                .filter(|c| c.entity != entity && c.tree == tree)
+                .filter_map(|c| Some((directional_score(direction, center, c.center)?, c)))
+                .min_by(|(a, _), (b, _)| a.total_cmp(b))
+                .map(|(_, c)| c),
+        }
+    }?;
+
+    // move the focus marker
+    let next = next.entity;
+    for entity in focused.iter().filter(|entity| *entity != next) { commands.entity(entity).remove::<Focused>(); }
+    if current.map(|(entity, _, _, _)| entity) != Some(next) { commands.entity(next).insert(Focused); }
+
+    Some(next)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_focus_cursor<U: LunexUi, C: LunexCursor>(
+    mut commands : Commands,
+    uis          : UiTrees<U>,
+    focused      : Query<FocusedWidget, (With<Focused>, FocusTargeterFilter<U, C>)>,
+    mut cursors  : Query<FocusCursorLocation, (With<C>, Without<Cursor>)>,
+){
+    // place the cursor at the center of the focused element, or outside all windows if nothing is focused
+    let location = focused
+        .iter()
+        .find_map(|(widget, in_tree)|
+            {
+                let (tree, ui) = uis.find(in_tree, widget)?;
+                let center = visible_widget_center(ui, widget)?;
+                Some(CursorLocation::new(uis.window(tree), center, center.invert_y()))
+            }
+        )
+        .unwrap_or_default();
+
+    for (entity, cursor_location) in cursors.iter_mut()
+    {
+        match cursor_location
+        {
+            Some(mut cursor_location) =>
+            {
+                if *cursor_location != location { *cursor_location = location; }
+            }
+            None => { commands.entity(entity).insert(location); }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks the interactive element that has focus.
+/// - Focus cursors (see [`FocusAppExt::register_focus_cursor()`]) are placed over the focused element, so it will be
+///   hovered and can be clicked by interaction sources that use a focus cursor.
+/// - Use [`MoveFocus`] and [`SetFocus`] to change the focused element.
+#[derive(Component, Default, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Focused;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Controls the tab order of interactive elements in the same UI tree.
+/// - Elements are visited in ascending order. Elements without this component have order `0`.
+/// - Ties are broken by widget position (top-to-bottom, then left-to-right).
+#[derive(Component, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct FocusOrder(pub i32);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Focus navigation directions.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum FocusDirection
+{
+    /// Next element in tab order (wraps around).
+    Next,
+    /// Previous element in tab order (wraps around).
+    Previous,
+    /// Closest element above the focused element in the same UI tree.
+    Up,
+    /// Closest element below the focused element in the same UI tree.
+    Down,
+    /// Closest element left of the focused element in the same UI tree.
+    Left,
+    /// Closest element right of the focused element in the same UI tree.
+    Right,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Move the [`Focused`] marker between visible interactive elements targeted by the `U`/`C` UI/cursor pair.
+/// - Candidates are elements in UI trees that can be seen from the window of the focused element (or the focused
+///   window if there is no focused element). Disabled elements are skipped.
+/// - If no element is focused, then the first element in tab order is focused (or the last for
+///   [`FocusDirection::Previous`]).
+pub struct MoveFocus<U: LunexUi, C: LunexCursor>
+{
+    direction : FocusDirection,
+    _p        : PhantomData<(U, C)>,
+}
+
+impl<U: LunexUi, C: LunexCursor> MoveFocus<U, C>
+{
+    pub fn new(direction: FocusDirection) -> Self { Self{ direction, _p: PhantomData } }
+}
+
+impl<U: LunexUi, C: LunexCursor> Command for MoveFocus<U, C>
+{
+    fn apply(self, world: &mut World)
+    {
+        syscall(world, self.direction, move_focus::<U, C>);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Move the [`Focused`] marker to a specific entity, or remove it from all entities.
+pub struct SetFocus(pub Option<Entity>);
+
+impl Command for SetFocus
+{
+    fn apply(self, world: &mut World)
+    {
+        let focused: Vec<Entity> = world.query_filtered::<Entity, With<Focused>>().iter(world).collect();
+        for entity in focused.into_iter().filter(|entity| Some(*entity) != self.0)
+        {
+            world.entity_mut(entity).remove::<Focused>();
+        }
+
+        let Some(entity) = self.0 else { return; };
+        let Some(mut entity_mut) = world.get_entity_mut(entity)
+        else
+        {
+            tracing::warn!("tried to focus a non-existent entity: {:?}", entity);
+            return;
+        };
+        entity_mut.insert(Focused);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set that contains focus cursor updates. Runs in schedule `First` before [`InteractionSourceSet`].
+/// - Focus navigation systems should run before this set.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct FocusCursorSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub trait FocusAppExt
+{ fn register_focus_cursor<U: LunexUi, C: LunexCursor>(&mut self) -> &mut Self; }
+
+impl FocusAppExt for App
+{
+    /// Register `C` as a focus cursor for UI `U`.
+    /// - The [`CursorLocation`] of entities with `C` is placed at the center of the [`Focused`] element (if it is
+    ///   targeted by `U`/`C`). Interaction sources that use `C` will hover and click the focused element.
+    /// - Focus cursor entities must not have a `bevy_lunex::Cursor`, since those follow the mouse.
+    fn register_focus_cursor<U: LunexUi, C: LunexCursor>(&mut self) -> &mut Self
+    {
+        self.configure_sets(First, FocusCursorSet.before(InteractionSourceSet))
+            .add_systems(First, update_focus_cursor::<U, C>.in_set(FocusCursorSet))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -13,7 +13,7 @@ use bevy_lunex::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn sysid(handle: &AutoDespawnSignal) -> SysId
+fn sysid<I>(handle: &AutoDespawnSignal) -> SysId<I>
 {
     SysId::new(handle.entity())
 }
@@ -144,6 +144,22 @@ fn check_hover_invariants(builder: &InteractiveElementBuilder) -> Result<(), Int
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+fn check_drag_invariants(builder: &InteractiveElementBuilder) -> Result<(), InteractiveElementBuilderError>
+{
+    // drags are driven by presses
+    if builder.element_is_draggable() && !builder.element_is_pressable()
+    { return Err(InteractiveElementBuilderError::MissingPressActivator); }
+
+    // drops are received by drop targets
+    if builder.on_drop_callback.is_some() && builder.drop_target.is_none()
+    { return Err(InteractiveElementBuilderError::MissingDropTarget); }
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
 fn toggle_widget_pack_visibility<Ui: LunexUi>(
     ui          : &mut UiTree<Ui>,
     on_widget   : Option<Widget>,
@@ -184,6 +200,7 @@ fn update_interactive_element_visibility<Ui: LunexUi>(
     pressed                : Query<&Pressed>,
     selected               : Query<(), With<Selected>>,
     hovered                : Query<(), With<Hovered>>,
+    in_tree                : Query<&InUiTree>,
     mut uis                : Query<&mut UiTree<Ui>>,
 ) -> Result<(), ()>
 {
@@ -208,7 +225,11 @@ fn update_interactive_element_visibility<Ui: LunexUi>(
     };
 
     // toggle visibility
-    let mut ui = uis.get_single_mut().or(Err(()))?;  //todo: uis.get_mut(widgets.get(entity).ui_entity())
+    let mut ui = match in_tree.get(entity)
+    {
+        Ok(in_tree) => uis.get_mut(in_tree.0).or(Err(()))?,
+        Err(_)      => uis.get_single_mut().or(Err(()))?,
+    };
     toggle_widget_pack_visibility(&mut ui, widget, pack)?;
     Ok(())
 }
@@ -293,7 +314,7 @@ fn hover_fixer(
         )
     { return; }
 
-    commands.add(unhover_callback.clone());
+    commands.add(unhover_callback.call_on(element_entity));
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -328,7 +349,68 @@ fn prepare_hover_fixer(
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn maybe_build_action_start_press<H, V>(
+/// Prepare drag starter (starts dragging the element and invokes its `CallbackWith<OnDragStart, DragInfo>`).
+fn prepare_drag_starter<S: InteractionSource>(
+    need_drag      : bool,
+    element_entity : Entity,
+) -> impl Fn(&mut World) + Clone + Send + Sync + 'static
+{
+    move |world: &mut World|
+    {
+        if !need_drag { return; }
+        let Some(info) = syscall(world, element_entity, start_drag::<S>) else { return; };
+
+        // invoke user-defined callback
+        let _ = try_callback_with::<OnDragStart, DragInfo>(world, element_entity, info);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Prepare drag updater (updates the element's drag and invokes its `CallbackWith<OnDrag, DragInfo>`).
+fn prepare_drag_updater<S: InteractionSource>(
+    need_drag      : bool,
+    element_entity : Entity,
+) -> impl Fn(&mut World) + Clone + Send + Sync + 'static
+{
+    move |world: &mut World|
+    {
+        if !need_drag { return; }
+        let Some(info) = syscall(world, element_entity, update_drag::<S>) else { return; };
+
+        // invoke user-defined callback
+        let _ = try_callback_with::<OnDrag, DragInfo>(world, element_entity, info);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Prepare drag ender (ends the element's drag, drops it on the drop target, and invokes its
+/// `CallbackWith<OnDragEnd, DragInfo>`).
+/// - The drag ender takes `true` if the drag should be dropped (on unpress), or `false` if it was aborted.
+fn prepare_drag_ender(
+    need_drag      : bool,
+    element_entity : Entity,
+) -> impl Fn(&mut World, bool) + Clone + Send + Sync + 'static
+{
+    move |world: &mut World, drop: bool|
+    {
+        if !need_drag { return; }
+        let Some(info) = syscall(world, (element_entity, drop), end_drag) else { return; };
+
+        // drop on the drop target
+        if let Some(drop_target) = info.drop_target()
+        { let _ = try_callback_with::<OnDrop, DragInfo>(world, drop_target, info); }
+
+        // invoke user-defined callback
+        let _ = try_callback_with::<OnDragEnd, DragInfo>(world, element_entity, info);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn maybe_build_action_start_press<H, D, V>(
     need_press                   : bool,
     despawner                    : &AutoDespawner,
     entity_commands              : &mut EntityCommands,
@@ -337,17 +419,20 @@ fn maybe_build_action_start_press<H, V>(
     no_hover_on_pressed          : bool,
     no_hover_on_pressed_selected : bool,
     hover_fixer                  : &H,
+    drag_starter                 : &D,
     startpress_callback          : CallbackSystem<(), ()>,
     update_widget_visibility     : &V,
 )
 where
     H: Fn(&mut World) -> () + Clone + Send + Sync + 'static,
+    D: Fn(&mut World) + Clone + Send + Sync + 'static,
     V: Fn(&mut World) -> () + Clone + Send + Sync + 'static 
 {
     // check if action is needed
     if !need_press { return; }
 
     let hover_fixer = if no_hover_on_pressed || no_hover_on_pressed_selected { Some(hover_fixer.clone()) } else { None };
+    let drag_starter = drag_starter.clone();
     let vis_updater = update_widget_visibility.clone();
 
     // register the callback
@@ -366,6 +451,9 @@ where
                 // fix hover state
                 if let Some(hover_fixer) = &hover_fixer { hover_fixer(world); }
 
+                // start dragging
+                drag_starter(world);
+
                 // invoke user-defined callback
                 if let Some(h) = &startpress_callback_handle { let _ = spawned_syscall::<(), ()>(world, sysid(h), ()); }
 
@@ -381,7 +469,7 @@ where
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn maybe_build_action_unpress<H, V>(
+fn maybe_build_action_unpress<H, D, V>(
     need_press                   : bool,
     despawner                    : &AutoDespawner,
     entity_commands              : &mut EntityCommands,
@@ -390,17 +478,20 @@ fn maybe_build_action_unpress<H, V>(
     no_hover_on_pressed          : bool,
     no_hover_on_pressed_selected : bool,
     hover_fixer                  : &H,
+    drag_ender                   : &D,
     unpress_callback             : CallbackSystem<(), ()>,
     update_widget_visibility     : &V,
 )
 where
     H: Fn(&mut World) -> () + Clone + Send + Sync + 'static,
+    D: Fn(&mut World, bool) + Clone + Send + Sync + 'static,
     V: Fn(&mut World) -> () + Clone + Send + Sync + 'static 
 {
     // check if action is needed
     if !need_press { return; }
 
     let hover_fixer = if no_hover_on_pressed || no_hover_on_pressed_selected { Some(hover_fixer.clone()) } else { None };
+    let drag_ender = drag_ender.clone();
     let vis_updater = update_widget_visibility.clone();
 
     // register the callback
@@ -413,6 +504,9 @@ where
                 // try to remove `Pressed` from entity
                 let Some(_) = try_remove_component_from_entity::<Pressed>(world, element_entity) else { return; };
 
+                // end dragging and drop
+                drag_ender(world, true);
+
                 // [option] action: select
                 if select_on_unpress { let _ = try_callback::<Select>(world, element_entity); }
 
@@ -434,7 +528,7 @@ where
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn maybe_build_action_abort_press<H, V>(
+fn maybe_build_action_abort_press<H, D, V>(
     need_press                   : bool,
     despawner                    : &AutoDespawner,
     entity_commands              : &mut EntityCommands,
@@ -442,11 +536,13 @@ fn maybe_build_action_abort_press<H, V>(
     no_hover_on_pressed          : bool,
     no_hover_on_pressed_selected : bool,
     hover_fixer                  : &H,
+    drag_ender                   : &D,
     abortpress_callback          : CallbackSystem<(), ()>,
     update_widget_visibility     : &V,
 )
 where
     H: Fn(&mut World) -> () + Clone + Send + Sync + 'static,
+    D: Fn(&mut World, bool) + Clone + Send + Sync + 'static,
     V: Fn(&mut World) -> () + Clone + Send + Sync + 'static 
 {
     // check if action is needed
@@ -454,6 +550,7 @@ where
     if !need_press { return; }
 
     let hover_fixer = if no_hover_on_pressed || no_hover_on_pressed_selected { Some(hover_fixer.clone()) } else { None };
+    let drag_ender = drag_ender.clone();
     let vis_updater = update_widget_visibility.clone();
 
     // register the callback
@@ -466,6 +563,9 @@ where
                 // try to remove `Pressed` from entity
                 let Some(_) = try_remove_component_from_entity::<Pressed>(world, element_entity) else { return; };
 
+                // end dragging without dropping
+                drag_ender(world, false);
+
                 // fix hover state
                 if let Some(hover_fixer) = &hover_fixer { hover_fixer(world); }
 
@@ -656,7 +756,7 @@ fn maybe_build_responder_on_click_hold(
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn maybe_build_responder_on_click_hold_home<H, V>(
+fn maybe_build_responder_on_click_hold_home<H, D, V>(
     need_press                   : bool,
     despawner                    : &AutoDespawner,
     entity_commands              : &mut EntityCommands,
@@ -664,18 +764,21 @@ fn maybe_build_responder_on_click_hold_home<H, V>(
     no_hover_on_pressed          : bool,
     no_hover_on_pressed_selected : bool,
     hover_fixer                  : &H,
+    drag_updater                 : &D,
     press_home_start_callback    : CallbackSystem<(), ()>,
     press_home_callback          : CallbackSystem<(), ()>,
     update_widget_visibility     : &V,
 )
 where
     H: Fn(&mut World) -> () + Clone + Send + Sync + 'static,
+    D: Fn(&mut World) + Clone + Send + Sync + 'static,
     V: Fn(&mut World) -> () + Clone + Send + Sync + 'static 
 {
     // check if responder is needed
     if !need_press { return; }
 
     let hover_fixer = if no_hover_on_pressed || no_hover_on_pressed_selected { Some(hover_fixer.clone()) } else { None };
+    let drag_updater = drag_updater.clone();
     let vis_updater = update_widget_visibility.clone();
 
     // register the callbacks
@@ -686,6 +789,9 @@ where
     let on_click_hold_home_callback = Callback::<OnClickHoldHome>::new(
             move |world: &mut World|
             {
+                // update drag
+                drag_updater(world);
+
                 // invoke user-defined callback: press home (always)
                 if let Some(h) = &press_home_handle { let _ = spawned_syscall::<(), ()>(world, sysid(h), ()); }
 
@@ -712,7 +818,7 @@ where
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn maybe_build_responder_on_click_hold_away<H, V>(
+fn maybe_build_responder_on_click_hold_away<H, D, V>(
     need_press                     : bool,
     despawner                      : &AutoDespawner,
     entity_commands                : &mut EntityCommands,
@@ -723,6 +829,7 @@ fn maybe_build_responder_on_click_hold_away<H, V>(
     no_hover_on_pressed            : bool,
     no_hover_on_pressed_selected   : bool,
     hover_fixer                    : &H,
+    drag_updater                   : &D,
     press_away_start_callback      : CallbackSystem<(), ()>,
     press_away_always_callback     : CallbackSystem<(), ()>,
     press_away_present_callback    : CallbackSystem<(), ()>,
@@ -731,12 +838,14 @@ fn maybe_build_responder_on_click_hold_away<H, V>(
 )
 where
     H: Fn(&mut World) -> () + Clone + Send + Sync + 'static,
+    D: Fn(&mut World) + Clone + Send + Sync + 'static,
     V: Fn(&mut World) -> () + Clone + Send + Sync + 'static 
 {
     // check if responder is needed
     if !need_press { return; }
 
     let hover_fixer = if no_hover_on_pressed || no_hover_on_pressed_selected { Some(hover_fixer.clone()) } else { None };
+    let drag_updater = drag_updater.clone();
     let vis_updater = update_widget_visibility.clone();
 
     // register the callbacks
@@ -749,6 +858,9 @@ where
     let on_click_hold_away_callback = CallbackWith::<OnClickHoldAway, bool>::new(
             move |world: &mut World, is_present: bool|
             {
+                // update drag
+                drag_updater(world);
+
                 // [option] action: abort press
                 if abort_press_on_press_away { let _ = try_callback::<AbortPress>(world, element_entity); return; }
 
@@ -964,6 +1076,8 @@ pub enum InteractiveElementBuilderError
     InconsistentUnPressUnclick,
     /// A `no_hover_on_*` setting is active but no reason to track hovers was given.
     MissingHoverReason,
+    /// An `on_drop` callback was given but the element is not a drop target.
+    MissingDropTarget,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -1022,6 +1136,7 @@ impl InteractiveElementWidgetPack
 /// own using the element's callbacks. If you want multiple interaction sources to control the same element, then you
 /// can add an `InteractiveElement<[source]>` component to the element entity for each additional source. Note that
 /// an interaction source will only work for an element if the element's parent UI tree has the source's `LunexUi` tag.
+/// If several UI trees share a tag (e.g. one per window), use `in_ui_tree` to tie the element to its tree.
 ///
 /// When it comes to deciding which element in a stack of overlapping elements should respond to an interaction source
 /// event, we use 'interactive element targeting' based on a {`LunexUi`, `LunexCursor`} pair. Only the highest element
@@ -1036,6 +1151,8 @@ impl InteractiveElementWidgetPack
 ///
 /// - `MouseLButton`: {clicks: mouse left button, hovers: mouse pointer}
 /// - `MouseRButton`: {clicks: mouse right button, hovers: mouse pointer}
+/// - `KeyboardConfirm`/`GamepadConfirm`: {clicks: confirm keys/buttons, hovers: focus cursor}
+/// - `TouchSource`: {clicks: touch in a touch slot, hovers: touch point}
 ///
 ///
 /// ## Implementation comments
@@ -1069,11 +1186,15 @@ impl InteractiveElementWidgetPack
 /// - A `on_unclick_callback` can only be added to the builder if a press activator and deactivator are specified, since
 ///   unclicking only makes sense in the context of pressing (and otherwise it would be non-obvious what is being unclicked
 ///   between the element and the press away zone).
+/// - Drags are tied to the pressed state: a drag starts when the element is pressed, updates every tick while pressed,
+///   drops when unpressed, and ends without dropping when press is aborted. To drop away from the element, use
+///   `unpress_on_unclick_home_and_away` or a press home zone that covers all drop targets.
 ///
 #[derive(Default)]
 pub struct InteractiveElementBuilder
 {
     widget_pack                    : InteractiveElementWidgetPack,
+    ui_tree                        : Option<Entity>,
 
     press_home_zone                : Option<Widget>,
     press_on_click                 : bool,
@@ -1117,6 +1238,13 @@ pub struct InteractiveElementBuilder
 
     select_callback                : CallbackSystem<(), ()>,
     deselect_callback              : CallbackSystem<(), ()>,
+
+    drag_payload                   : Option<DragPayload>,
+    drop_target                    : Option<DropTarget>,
+    on_drag_start_callback         : Option<CallbackWith<OnDragStart, DragInfo>>,
+    on_drag_callback               : Option<CallbackWith<OnDrag, DragInfo>>,
+    on_drag_end_callback           : Option<CallbackWith<OnDragEnd, DragInfo>>,
+    on_drop_callback               : Option<CallbackWith<OnDrop, DragInfo>>,
 }
 
 impl InteractiveElementBuilder
@@ -1185,6 +1313,14 @@ impl InteractiveElementBuilder
         self
     }
 
+    /// Associate the element with a specific UI tree entity (see [`InUiTree`]).
+    /// - Required if multiple UI trees with the same `LunexUi` tag contain the element's widget path.
+    pub fn in_ui_tree(mut self, ui_tree: Entity) -> Self
+    {
+        self.ui_tree = Some(ui_tree);
+        self
+    }
+
     /// Add widget for zone where, if the element is pressed and the clicker is in the zone, then the element will be
     /// in state `Pressed::Home`. If pressed and outside the zone, the element will be in state `Pressed::Away`.
     /// - By default the 'press home zone' equals the element widget.
@@ -1511,6 +1647,67 @@ impl InteractiveElementBuilder
         self
     }
 
+    /// Attach a payload to the element. The payload is carried by drags of the element and can be inspected by drop
+    /// targets.
+    /// - Makes the element draggable (requires a press activator).
+    pub fn with_drag_payload<P: Send + Sync + 'static>(mut self, payload: P) -> Self
+    {
+        self.drag_payload = Some(DragPayload::new(payload));
+        self
+    }
+
+    /// Callback invoked when a drag of the element starts (when the element is pressed).
+    /// - Inserted as `CallbackWith<OnDragStart, DragInfo>`.
+    /// - Makes the element draggable (requires a press activator).
+    pub fn on_drag_start<Marker>(
+        mut self,
+        callback: impl IntoSystem<DragInfo, (), Marker> + Send + Sync + 'static,
+    ) -> Self
+    {
+        self.on_drag_start_callback = Some(CallbackWith::from_system(callback));
+        self
+    }
+
+    /// Callback invoked every tick while the element is being dragged (while the element is pressed).
+    /// - Inserted as `CallbackWith<OnDrag, DragInfo>`.
+    /// - Makes the element draggable (requires a press activator).
+    pub fn on_drag<Marker>(mut self, callback: impl IntoSystem<DragInfo, (), Marker> + Send + Sync + 'static) -> Self
+    {
+        self.on_drag_callback = Some(CallbackWith::from_system(callback));
+        self
+    }
+
+    /// Callback invoked when a drag of the element ends (when the element is unpressed or press is aborted).
+    /// - Inserted as `CallbackWith<OnDragEnd, DragInfo>`.
+    /// - The drag info's drop target is the target that received the drop, or `None` if there was no drop.
+    /// - Invoked after the drop target's `on_drop` callback.
+    /// - Makes the element draggable (requires a press activator).
+    pub fn on_drag_end<Marker>(
+        mut self,
+        callback: impl IntoSystem<DragInfo, (), Marker> + Send + Sync + 'static,
+    ) -> Self
+    {
+        self.on_drag_end_callback = Some(CallbackWith::from_system(callback));
+        self
+    }
+
+    /// Make the element a drop target for draggable elements.
+    /// - The element does not need to be interactive to be a drop target. Drop targets may also be added to plain widget
+    ///   entities with the [`DropTarget`] component.
+    pub fn as_drop_target(mut self, drop_target: DropTarget) -> Self
+    {
+        self.drop_target = Some(drop_target);
+        self
+    }
+
+    /// Callback invoked when a draggable element is dropped on this element.
+    /// - Inserted as `CallbackWith<OnDrop, DragInfo>`. Requires [`Self::as_drop_target()`].
+    pub fn on_drop<Marker>(mut self, callback: impl IntoSystem<DragInfo, (), Marker> + Send + Sync + 'static) -> Self
+    {
+        self.on_drop_callback = Some(CallbackWith::from_system(callback));
+        self
+    }
+
     /// Consume the builder to build interactivity into the element.
     /// - The builder returns an error if the build configuration is incomplete or inconsistent (e.g. a press activator
     ///   was specified but no press deactivator).
@@ -1529,11 +1726,13 @@ impl InteractiveElementBuilder
         check_press_invariants(&self)?;
         check_select_invariants(&self)?;
         check_hover_invariants(&self)?;
+        check_drag_invariants(&self)?;
 
         // check which handlers we need
         let need_press  = self.element_is_pressable();
         let need_select = self.element_is_selectable();
         let need_hover  = self.element_is_hoverable();
+        let need_drag   = self.element_is_draggable();
 
         // define the widget that will handle press-away events
         let press_home_zone = self.press_home_zone.unwrap_or_else(|| element_widget.clone());
@@ -1546,7 +1745,9 @@ impl InteractiveElementBuilder
                 )
             );
         if need_press { entity_commands.insert(PressHomeZone(press_home_zone)); }
-
+        if let Some(ui_tree) = self.ui_tree { entity_commands.insert(InUiTree(ui_tree)); }
+        if let Some(drag_payload) = self.drag_payload { entity_commands.insert(drag_payload); }
+        if let Some(drop_target) = self.drop_target { entity_commands.insert(drop_target); }
 
         // prepare visibility updater
         let element_entity = entity_commands.id();
@@ -1576,12 +1777,24 @@ impl InteractiveElementBuilder
                 self.no_hover_on_pressed_selected,
             );
 
+        // prepare drag handlers (start/update/end the drag of a pressed element)
+        let drag_starter = prepare_drag_starter::<S>(need_drag, element_entity);
+        let drag_updater = prepare_drag_updater::<S>(need_drag, element_entity);
+        let drag_ender = prepare_drag_ender(need_drag, element_entity);
+
+        // callbacks: on drag start/on drag/on drag end/on drop
+        if let Some(callback) = self.on_drag_start_callback { entity_commands.insert(callback); }
+        if let Some(callback) = self.on_drag_callback { entity_commands.insert(callback); }
+        if let Some(callback) = self.on_drag_end_callback { entity_commands.insert(callback); }
+        if let Some(callback) = self.on_drop_callback { entity_commands.insert(callback); }
+
 
         // action: start press
         //need_press
         //add Pressed::Home component (leave if Pressed already exists)
         //[option] action: select
         //hover fixer
+        //start drag
         //callback: on press start
         //update visibility
         maybe_build_action_start_press(
@@ -1593,6 +1806,7 @@ impl InteractiveElementBuilder
                 self.no_hover_on_pressed,
                 self.no_hover_on_pressed_selected,
                 &hover_fixer,
+                &drag_starter,
                 self.startpress_callback,
                 &update_widget_visibility,
             );
@@ -1600,6 +1814,7 @@ impl InteractiveElementBuilder
         // action: unpress
         //need_press
         //remove Pressed component (leave if nothing removed)
+        //end drag and drop
         //[option] action: select
         //hover fixer
         //callback: on unpress
@@ -1613,6 +1828,7 @@ impl InteractiveElementBuilder
                 self.no_hover_on_pressed,
                 self.no_hover_on_pressed_selected,
                 &hover_fixer,
+                &drag_ender,
                 self.unpress_callback,
                 &update_widget_visibility,
             );
@@ -1620,6 +1836,7 @@ impl InteractiveElementBuilder
         // action: abort press
         //need_press (note: if need_press then always need this action in case of window focus change)
         //remove Pressed component (leave if nothing removed)
+        //end drag without dropping
         //hover fixer
         //callback: on abort press
         //update visibility
@@ -1631,6 +1848,7 @@ impl InteractiveElementBuilder
                 self.no_hover_on_pressed,
                 self.no_hover_on_pressed_selected,
                 &hover_fixer,
+                &drag_ender,
                 self.abortpress_callback,
                 &update_widget_visibility,
             );
@@ -1700,6 +1918,7 @@ impl InteractiveElementBuilder
 
         // responder: on click hold home w/ Pressed component
         //need_press
+        //update drag
         //callback: press home
         //set Pressed component to Home (leave if not pressed or no change)
         //hover fixer
@@ -1713,6 +1932,7 @@ impl InteractiveElementBuilder
                 self.no_hover_on_pressed,
                 self.no_hover_on_pressed_selected,
                 &hover_fixer,
+                &drag_updater,
                 self.press_home_start_callback,
                 self.press_home_callback,
                 &update_widget_visibility,
@@ -1720,6 +1940,7 @@ impl InteractiveElementBuilder
 
         // responder: on click hold away w/ Pressed component
         //need_press
+        //update drag
         //[option: always]: action: abort press, then leave
         //[option: obstructed]: action: abort press, then leave
         //[option] action: unpress, then leave
@@ -1746,6 +1967,7 @@ impl InteractiveElementBuilder
                 self.no_hover_on_pressed,
                 self.no_hover_on_pressed_selected,
                 &hover_fixer,
+                &drag_updater,
                 self.press_away_start_callback,
                 self.press_away_always_callback,
                 self.press_away_present_callback,
@@ -1851,6 +2073,14 @@ impl InteractiveElementBuilder
         self.on_hover_callback.has_system()                      ||
         self.on_unhover_callback.has_system()
     }
+
+    fn element_is_draggable(&self) -> bool
+    {
+        self.drag_payload.is_some()                ||
+        self.on_drag_start_callback.is_some()      ||
+        self.on_drag_callback.is_some()            ||
+        self.on_drag_end_callback.is_some()
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
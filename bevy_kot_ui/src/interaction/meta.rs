@@ -5,7 +5,6 @@ use bevy_kot_ecs::*;
 //third-party shortcuts
 use bevy::ecs::system::Command;
 use bevy::prelude::*;
-use bevy_lunex::cursor_update;
 
 //standard shortcuts
 use std::fmt::Debug;
@@ -147,6 +146,8 @@ impl RegisterInteractionSourceExt for App
     ///   cursor-based key bindings). If the source is removed then the associated interaction pipeline will be disabled.
     /// - If ordering matters between interaction sources, apply ordering constraints to the pertinent
     ///   `interaction_pipeline<[source]>` systems in schedule `First`.
+    /// - Cursor locations are refreshed with [`update_cursor_locations()`] before the pipeline runs, so cursors can
+    ///   interact with UI trees in any window.
     fn register_interaction_source<S: InteractionSource>(&mut self, interaction_source: S) -> &mut Self
     {
         self.setup_auto_despawn()
@@ -154,7 +155,7 @@ impl RegisterInteractionSourceExt for App
             .insert_resource(interaction_source)
             .add_systems(First,
                 (
-                    cursor_update,  //todo: this is duplicate work, but we need fresh cursor state
+                    update_cursor_locations,
                     interaction_pipeline::<S>,
                 )
                     .chain()
@@ -1,15 +1,21 @@
 //module tree
 mod core;
 mod cursor_position;
+mod drag_drop;
+mod focus;
 mod interactive_element_builder;
 mod meta;
 mod pipeline;
 mod tag_types;
+mod ui_trees;
 
 //API exports
 pub use crate::interaction::core::*;
 pub use crate::interaction::cursor_position::*;
+pub use crate::interaction::drag_drop::*;
+pub use crate::interaction::focus::*;
 pub use crate::interaction::interactive_element_builder::*;
 pub use crate::interaction::meta::*;
 pub use crate::interaction::pipeline::*;
 pub use crate::interaction::tag_types::*;
+pub use crate::interaction::ui_trees::*;
@@ -3,7 +3,7 @@ use crate::*;
 use bevy_kot_ecs::*;
 
 //third-party shortcuts
-use bevy::ecs::system::StaticSystemParam;
+use bevy::ecs::system::{StaticSystemParam, SystemParam, SystemParamItem};
 use bevy::prelude::*;
 use bevy_lunex::prelude::*;
 
@@ -20,6 +20,7 @@ struct InteractionSourceInfoPack
     is_clicked        : bool,
     just_unclicked    : bool,
     target_is_hovered : bool,
+    tree              : Option<Entity>,
     depth_limit       : Option<f32>,
     targeted          : Option<Entity>,
 }
@@ -27,87 +28,128 @@ struct InteractionSourceInfoPack
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-//todo: how to handle UiTrees occluding each other?
-//todo: custom system param filter to get only widgets associated with ui in focused window ?? UIInFocusedWindow<LunexUi>
-fn try_get_interaction_source_info_pack<S: InteractionSource>(
-    ui              : Query<&UiTree<S::LunexUi>>,  //todo: InFocusedWindow
-    cursor_pos      : CursorPos<S::LunexCursor>,
-    source          : Res<S>,
-    source_param    : StaticSystemParam<S::SourceParam>,
-    barrier_param   : StaticSystemParam<<<S as InteractionSource>::LunexCursor as LunexCursor>::BarrierParam>,
-    element_param   : StaticSystemParam<<<S as InteractionSource>::LunexCursor as LunexCursor>::ElementParam>,
-    home_zone_param : StaticSystemParam<<<S as InteractionSource>::LunexCursor as LunexCursor>::HomeZoneParam>,
-    barrier_widgets : Query<
-        (Entity, &Widget),
-        Or<(With<UiInteractionBarrier<S::LunexUi>>, With<InteractionBarrier<S::LunexUi, S::LunexCursor>>)>
-    >,
-    unpressed_elements: Query<
-        (Entity, &Widget),
-        (Without<Pressed>, With<ElementInteractionTargeter<S::LunexUi, S::LunexCursor>>)
-    >,
-    pressed_elements: Query<
-        (Entity, &Widget, &PressHomeZone),
-        (With<Pressed>, With<ElementInteractionTargeter<S::LunexUi, S::LunexCursor>>)
-    >,
-) -> Option<InteractionSourceInfoPack>
+type SourceUi<S> = <S as InteractionSource>::LunexUi;
+pub(crate) type SourceCursor<S> = <S as InteractionSource>::LunexCursor;
+type SourceTargeter<S> = ElementInteractionTargeter<SourceUi<S>, SourceCursor<S>>;
+pub(crate) type SourceBarrierFilter<S> =
+    Or<(With<UiInteractionBarrier<SourceUi<S>>>, With<InteractionBarrier<SourceUi<S>, SourceCursor<S>>>)>;
+pub(crate) type TargetWidget = (Entity, &'static Widget, Option<&'static InUiTree>);
+type PressedTargetWidget = (Entity, &'static Widget, &'static PressHomeZone, Option<&'static InUiTree>);
+type PressedSourceFilter<S> = (With<Pressed>, With<ElementInteractionSource<S>>);
+type HoveredSourceFilter<S> = (With<Hovered>, With<ElementInteractionSource<S>>);
+
+#[derive(SystemParam)]
+struct TargetingParams<'w, 's, S: InteractionSource>
 {
-    // check that ui and cursor are available
-    // - note that the cursor is more likely to be unavailable than the ui tree
-    if !cursor_pos.available() || ui.is_empty() { return None; };
+    barrier_param      : StaticSystemParam<'w, 's, <SourceCursor<S> as LunexCursor>::BarrierParam>,
+    element_param      : StaticSystemParam<'w, 's, <SourceCursor<S> as LunexCursor>::ElementParam>,
+    home_zone_param    : StaticSystemParam<'w, 's, <SourceCursor<S> as LunexCursor>::HomeZoneParam>,
+    barrier_widgets    : Query<'w, 's, TargetWidget, SourceBarrierFilter<S>>,
+    unpressed_elements : Query<'w, 's, TargetWidget, (Without<Pressed>, With<SourceTargeter<S>>)>,
+    pressed_elements   : Query<'w, 's, PressedTargetWidget, (With<Pressed>, With<SourceTargeter<S>>)>,
+}
 
-    let Ok(ui) = ui.get_single()
-    else { tracing::error!("multiple uis with the same tag detected in the focused window"); return None; };
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
 
-    // get positions
-    let Some(cpos_screen) = cursor_pos.get_screen()
-    else { tracing::error!("unable to access the cursor's screen position"); return None; };
-    let Some(cpos_world) = cursor_pos.get_world()
-    else { tracing::error!("unable to access the cursor's world position"); return None; };
+#[derive(Copy, Clone, Debug, Default)]
+struct TreeHits
+{
+    depth_limit       : Option<f32>,
+    targeted          : Option<Entity>,
+    target_is_hovered : bool,
+}
 
-    // find top-most barrier widget under the cursor
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Find the depth of the top-most interaction barrier under the cursor in one UI tree.
+///
+/// Returns `None` if the cursor doesn't intersect any barriers in the tree.
+pub(crate) fn find_barrier_depth<S: InteractionSource>(
+    ui_entity       : Entity,
+    ui              : &UiTree<S::LunexUi>,
+    cpos_screen     : Vec2,
+    cpos_world      : Vec2,
+    barrier_widgets : &Query<TargetWidget, SourceBarrierFilter<S>>,
+    barrier_param   : &SystemParamItem<<SourceCursor<S> as LunexCursor>::BarrierParam>,
+) -> Option<f32>
+{
     let mut depth_limit: Option<f32> = None;
 
-    for (entity, widget) in barrier_widgets.iter()
+    for (entity, widget, in_tree) in barrier_widgets.iter()
     {
+        // check tree
+        if !belongs_to_tree(in_tree, ui_entity) { continue; }
+
         // check visibility
-        let Ok(widget_branch) = widget.fetch(&ui) else { continue; };
+        let Ok(widget_branch) = widget.fetch(ui) else { continue; };
         if !widget_branch.is_visible() { continue; }
 
         // check if barrier widget intersects with the cursor
         let Ok(Some(widget_depth)) = S::LunexCursor::cursor_intersects_barrier(
                 cpos_screen,
                 cpos_world,
-                &ui,
+                ui,
                 widget,
                 entity,
                 depth_limit,
                 widget_branch.get_depth(),
-                &barrier_param,
+                barrier_param,
             ) else { continue; };
 
         depth_limit = Some(widget_depth);
     }
 
+    depth_limit
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Find the barrier depth and the top-most element under the cursor in one UI tree.
+///
+/// Returns `None` if the cursor doesn't intersect any barriers or elements in the tree.
+fn try_get_tree_hits<S: InteractionSource>(
+    ui_entity   : Entity,
+    ui          : &UiTree<S::LunexUi>,
+    cpos_screen : Vec2,
+    cpos_world  : Vec2,
+    targeting   : &TargetingParams<S>,
+) -> Option<TreeHits>
+{
+    // find top-most barrier widget under the cursor
+    let depth_limit = find_barrier_depth::<S>(
+            ui_entity,
+            ui,
+            cpos_screen,
+            cpos_world,
+            &targeting.barrier_widgets,
+            &targeting.barrier_param,
+        );
+
     // get highest unpressed element under the cursor
     let mut top_unpressed: Option<Entity> = None;
     let mut target_limit = depth_limit;
 
-    for (entity, widget) in unpressed_elements.iter()
+    for (entity, widget, in_tree) in targeting.unpressed_elements.iter()
     {
+        // check tree
+        if !belongs_to_tree(in_tree, ui_entity) { continue; }
+
         // check visibility
-        let Ok(widget_branch) = widget.fetch(&ui) else { continue; };
+        let Ok(widget_branch) = widget.fetch(ui) else { continue; };
         if !widget_branch.is_visible() { continue; }
 
         // check if element widget intersects with the cursor
         let Ok(Some(widget_depth)) = S::LunexCursor::cursor_intersects_element(
                 cpos_screen,
                 cpos_world,
-                &ui,
+                ui,
                 widget,
                 entity,
                 target_limit,
                 widget_branch.get_depth(),
-                &element_param,
+                &targeting.element_param,
             ) else { continue; };
 
         top_unpressed = Some(entity);
@@ -117,74 +159,116 @@ fn try_get_interaction_source_info_pack<S: InteractionSource>(
     // get highest pressed element whose press home zone is under the cursor
     let mut top_pressed: Option<Entity> = None;
 
-    for (entity, _, press_home_zone) in pressed_elements.iter()
+    for (entity, _, press_home_zone, in_tree) in targeting.pressed_elements.iter()
     {
+        // check tree
+        if !belongs_to_tree(in_tree, ui_entity) { continue; }
+
         // check visibility
-        let Ok(widget_branch) = press_home_zone.0.fetch(&ui) else { continue; };
+        let Ok(widget_branch) = press_home_zone.0.fetch(ui) else { continue; };
         if !widget_branch.is_visible() { continue; }
 
         // check if press home zone widget intersects with the cursor
         let Ok(Some(widget_depth)) = S::LunexCursor::cursor_intersects_press_home_zone(
                 cpos_screen,
                 cpos_world,
-                &ui,
+                ui,
                 &press_home_zone.0,
                 entity,
                 target_limit,
                 widget_branch.get_depth(),
-                &home_zone_param,
+                &targeting.home_zone_param,
             ) else { continue; };
 
         top_pressed  = Some(entity);
         target_limit = Some(widget_depth);
     }
 
+    // check if the cursor hit anything in this tree
+    if depth_limit.is_none() && top_unpressed.is_none() && top_pressed.is_none() { return None; }
+
     // set final target
-    let targeted;
-    let target_is_hovered;
+    let Some(pressed_entity) = top_pressed
+    else { return Some(TreeHits{ depth_limit, targeted: top_unpressed, target_is_hovered: top_unpressed.is_some() }); };
+
+    // get widget and branch
+    let Ok((_, widget, _, _)) = targeting.pressed_elements.get(pressed_entity)
+    else { tracing::error!("pressed entity is missing"); return None; };
+
+    let Ok(widget_branch) = widget.fetch(ui)
+    else { tracing::error!("pressed entity's widget branch is missing"); return None; };
+
+    // check if target's element is hovered
+    let target_is_hovered = matches!(
+            S::LunexCursor::cursor_intersects_element(
+                cpos_screen,
+                cpos_world,
+                ui,
+                widget,
+                pressed_entity,
+                None,  //no depth limit
+                widget_branch.get_depth(),
+                &targeting.element_param,
+            ),
+            Ok(Some(_))
+        );
+
+    Some(TreeHits{ depth_limit, targeted: Some(pressed_entity), target_is_hovered })
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Get the interaction state for the source.
+///
+/// UI trees that can be seen from the cursor's window are tested from top-most to bottom-most. The first tree where
+/// the cursor intersects a barrier or element occludes the trees below it.
+fn try_get_interaction_source_info_pack<S: InteractionSource>(
+    uis             : UiTrees<S::LunexUi>,
+    cursor_pos      : CursorPos<S::LunexCursor>,
+    source          : Res<S>,
+    source_param    : StaticSystemParam<S::SourceParam>,
+    targeting       : TargetingParams<S>,
+) -> Option<InteractionSourceInfoPack>
+{
+    // check that ui and cursor are available
+    // - note that the cursor is more likely to be unavailable than the ui tree
+    if !cursor_pos.available() || uis.is_empty() { return None; };
+
+    // get positions
+    let Some(cpos_screen) = cursor_pos.get_screen()
+    else { tracing::error!("unable to access the cursor's screen position"); return None; };
+    let Some(cpos_world) = cursor_pos.get_world()
+    else { tracing::error!("unable to access the cursor's world position"); return None; };
+
+    // find the top-most tree under the cursor
+    let mut tree = None;
+    let mut hits = TreeHits::default();
 
-    match top_pressed
+    for (ui_entity, ui) in uis.in_window(cursor_pos.window())
     {
-        Some(pressed_entity) =>
-        {
-            // target: pressed entity
-            targeted = Some(pressed_entity);
-
-            // get widget and branch
-            let Ok((_, widget, _)) = pressed_elements.get(pressed_entity)
-            else { tracing::error!("pressed entity is missing"); return None; };
-
-            let Ok(widget_branch) = widget.fetch(&ui)
-            else { tracing::error!("pressed entity's widget branch is missing"); return None; };
-
-            // check if target's element is hovered
-            if let Ok(Some(_)) = S::LunexCursor::cursor_intersects_element(
-                    cpos_screen,
-                    cpos_world,
-                    &ui,
-                    widget,
-                    pressed_entity,
-                    None,  //no depth limit
-                    widget_branch.get_depth(),
-                    &element_param,
-                ) { target_is_hovered = true; }
-            else { target_is_hovered = false; }
-        }
-        None =>
-        {
-            targeted          = top_unpressed;
-            target_is_hovered = top_unpressed.is_some();
-        }
+        let Some(tree_hits) = try_get_tree_hits::<S>(
+                ui_entity,
+                ui,
+                cpos_screen,
+                cpos_world,
+                &targeting,
+            ) else { continue; };
+
+        tree = Some(ui_entity);
+        hits = tree_hits;
+        break;
     }
 
     // assemble the info pack
     Some(InteractionSourceInfoPack{
-            just_clicked   : source.just_clicked(&source_param),
-            is_clicked     : source.is_clicked(&source_param),
-            just_unclicked : source.just_unclicked(&source_param),
-            target_is_hovered,
-            depth_limit,
-            targeted,
+            just_clicked      : source.just_clicked(&source_param),
+            is_clicked        : source.is_clicked(&source_param),
+            just_unclicked    : source.just_unclicked(&source_param),
+            target_is_hovered : hits.target_is_hovered,
+            tree,
+            depth_limit       : hits.depth_limit,
+            targeted          : hits.targeted,
         })
 }
 
@@ -213,7 +297,7 @@ fn handle_just_clicked<S: InteractionSource>(
     let Ok(callback) = widgets.get(highest_entity) else { return; };
 
     // queue the callback
-    commands.add(callback.clone());
+    commands.add(callback.call_on(highest_entity));
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -241,7 +325,7 @@ fn handle_is_clicked<S: InteractionSource>(
     let Ok(callback) = widgets.get(highest_entity) else { return; };
 
     // queue the callback
-    commands.add(callback.clone());
+    commands.add(callback.call_on(highest_entity));
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -268,7 +352,7 @@ fn handle_is_clicked_home<S: InteractionSource>(
     let Ok(callback) = widgets.get(highest_entity) else { return; };
 
     // queue the callback
-    commands.add(callback.clone());
+    commands.add(callback.call_on(highest_entity));
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -277,9 +361,9 @@ fn handle_is_clicked_home<S: InteractionSource>(
 fn handle_is_clicked_away<S: InteractionSource>(
     In(info_pack) : In<InteractionSourceInfoPack>,
     mut commands  : Commands,
-    ui            : Query<&UiTree<S::LunexUi>>,  //todo: InFocusedWindow
+    uis           : UiTrees<S::LunexUi>,
     widgets       : Query<
-        (Entity, &Widget, &CallbackWith<OnClickHoldAway, bool>),
+        (Entity, &Widget, &CallbackWith<OnClickHoldAway, bool>, Option<&InUiTree>),
         (With<Pressed>, With<ElementInteractionTargeter<S::LunexUi, S::LunexCursor>>, With<ElementInteractionSource<S>>)
     >,
 ){
@@ -289,24 +373,28 @@ fn handle_is_clicked_away<S: InteractionSource>(
     // check if there are any widgets with callbacks
     if widgets.is_empty() { return; }
 
-    // get the ui
-    let Ok(ui) = ui.get_single() else { tracing::error!("ui is missing"); return; };
-
     // find pressed widgets away from the cursor
-    for (entity, widget, callback) in widgets.iter()
+    for (entity, widget, callback, in_tree) in widgets.iter()
     {
         // skip widgets targeted by the cursor
         if info_pack.targeted == Some(entity) { continue; };
 
-        // skip widgets not associated with the current UI
-        let Ok(widget_branch) = widget.fetch(&ui) else { continue; };
+        // skip widgets not associated with a UI
+        let Some((ui_entity, ui)) = uis.find(in_tree, widget) else { continue; };
+        let Ok(widget_branch) = widget.fetch(ui) else { continue; };
 
-        // check if the widget is present (visible and above the cursor's interaction barrier)
+        // check if the widget is present (visible, and above the cursor's interaction barrier or not occluded by the
+        // cursor's tree)
+        // - `Option::is_none_or()` is avoided so older toolchains are supported
+        #[allow(clippy::unnecessary_map_or)]
+        let above_barrier = info_pack.depth_limit.map_or(true, |top| top <= widget_branch.get_depth());
         let is_present =
             widget_branch.is_visible() &&
-            'x : {
-                if let Some(top) = info_pack.depth_limit { if top > widget_branch.get_depth() { break 'x false } }
-                true
+            match info_pack.tree
+            {
+                Some(tree) if tree == ui_entity => above_barrier,
+                Some(tree) => !uis.occludes(tree, ui_entity),
+                None => true,
             };
 
         // queue the callback
@@ -366,7 +454,7 @@ fn handle_hover<S: InteractionSource>(
     let Ok(callback) = widgets.get(highest_entity) else { return; };
 
     // queue the callback
-    commands.add(callback.clone());
+    commands.add(callback.call_on(highest_entity));
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -390,7 +478,80 @@ fn handle_unhover<S: InteractionSource>(
         if (info_pack.targeted == Some(entity)) && info_pack.target_is_hovered { continue; };
 
         // queue the callback
-        commands.add(callback.clone());
+        commands.add(callback.call_on(entity));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone, Debug, Default)]
+struct InteractionCleanup
+{
+    abort_press : bool,
+    unhover     : bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct CleanupTracker
+{
+    initialized    : bool,
+    had_cursor     : bool,
+    focused_window : Option<Entity>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Detect if the source's cursor was lost or the focused window changed since the last pipeline run.
+fn detect_interaction_cleanup<S: InteractionSource>(
+    mut tracker : Local<CleanupTracker>,
+    source      : Res<S>,
+    cursor_pos  : CursorPos<S::LunexCursor>,
+    windows     : Query<(Entity, &Window)>,
+) -> InteractionCleanup
+{
+    // update the tracker
+    let has_cursor     = cursor_pos.available();
+    let focused_window = windows.iter().find(|(_, window)| window.focused).map(|(entity, _)| entity);
+
+    let cursor_lost    = tracker.initialized && tracker.had_cursor && !has_cursor;
+    let focus_changed  = tracker.initialized && tracker.focused_window != focused_window;
+    *tracker = CleanupTracker{ initialized: true, had_cursor: has_cursor, focused_window };
+
+    // apply the source's policy
+    let policy = source.cleanup_policy();
+
+    InteractionCleanup{
+        abort_press : (cursor_lost && policy.abort_press_on_cursor_lost)
+                        || (focus_changed && policy.abort_press_on_focus_change),
+        unhover     : (cursor_lost && policy.unhover_on_cursor_lost)
+                        || (focus_changed && policy.unhover_on_focus_change),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_interaction_cleanup<S: InteractionSource>(
+    In(cleanup)  : In<InteractionCleanup>,
+    mut commands : Commands,
+    pressed      : Query<(Entity, &Callback<AbortPress>), PressedSourceFilter<S>>,
+    hovered      : Query<(Entity, &Callback<OnUnHover>), HoveredSourceFilter<S>>,
+){
+    // abort presses
+    if cleanup.abort_press
+    {
+        for (entity, callback) in pressed.iter() { commands.add(callback.call_on(entity)); }
+    }
+
+    // unhover
+    if cleanup.unhover
+    {
+        for (entity, callback) in hovered.iter() { commands.add(callback.call_on(entity)); }
     }
 }
 
@@ -398,18 +559,19 @@ fn handle_unhover<S: InteractionSource>(
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Poll the interaction source and handle its current state.
+///
+/// If the source's cursor is disabled/despawned or the focused window changes, then pressed and hovered elements are
+/// cleaned up according to the source's [`InteractionCleanupPolicy`].
 pub fn interaction_pipeline<S: InteractionSource>(world: &mut World)
 {
+    // clean up element states if the cursor was lost or the focused window changed
+    // - do this before polling the source so elements can be re-hovered if the cursor is still over them
+    let cleanup = syscall(world, (), detect_interaction_cleanup::<S>);
+    if cleanup.abort_press || cleanup.unhover { syscall(world, cleanup, handle_interaction_cleanup::<S>); }
+
     // try get source info pack
     // - if we cannot get an info pack then the pipeline for this source is disabled
-    let Some(info_pack) = syscall(world, (), try_get_interaction_source_info_pack::<S>)
-    else
-    {
-        //todo: clean up Pressed/Hovered if focused window changed (abort press and force unhover)
-        //todo: clean up Pressed/Hovered if cursor is deactivated or despawned (abort press and force unhover)
-        //(maybe do this in separate system for all sources?)
-        return;
-    };
+    let Some(info_pack) = syscall(world, (), try_get_interaction_source_info_pack::<S>) else { return; };
 
     // [IF CLICKED]
     // handle source was just unclicked and there is an entity with `Pressed`
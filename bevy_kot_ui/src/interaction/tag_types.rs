@@ -141,6 +141,32 @@ pub struct OnHover;
 #[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
 pub struct OnUnHover;
 
+/// Callback invoked when a drag of the entity starts.
+///
+/// Callback added to interactive element: `CallbackWith<OnDragStart, DragInfo>`.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct OnDragStart;
+
+/// Callback invoked every tick while the entity is being dragged.
+///
+/// Callback added to interactive element: `CallbackWith<OnDrag, DragInfo>`.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct OnDrag;
+
+/// Callback invoked when a drag of the entity ends.
+///
+/// Callback added to interactive element: `CallbackWith<OnDragEnd, DragInfo>`.
+/// - Invoked after the drop target's [`OnDrop`] callback.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct OnDragEnd;
+
+/// Callback invoked when a draggable element is dropped on the entity.
+///
+/// Callback added to drop target: `CallbackWith<OnDrop, DragInfo>`.
+/// - Only invoked when the entity has a [`DropTarget`] that accepts the drag.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct OnDrop;
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Indicates an entity is disabled.
@@ -0,0 +1,143 @@
+//local shortcuts
+use crate::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_lunex::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+type UiTreeInfo<U> = (
+    Entity,
+    &'static UiTree<U>,
+    Has<Window>,
+    Option<&'static UiTreeWindow>,
+    Option<&'static UiTreeLayer>
+);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Associates a `bevy_lunex::UiTree<Ui>` with an OS window.
+/// - UI trees inserted on window entities are automatically associated with those windows.
+/// - UI trees that aren't associated with a window can be interacted with from any window.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct UiTreeWindow(pub Entity);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Controls occlusion between `bevy_lunex::UiTree<Ui>`s with the same tag in the same window.
+/// - Trees in higher layers occlude trees in lower layers wherever the cursor intersects an interaction barrier or
+///   element in the higher tree.
+/// - Trees without this component are in layer `0`. Ties are broken by entity order (newer entities are on top).
+#[derive(Component, Default, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct UiTreeLayer(pub i32);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Associates an interactive element or interaction barrier with a specific `bevy_lunex::UiTree<Ui>` entity.
+/// - Entities without this component are associated with every tree that contains their widget. This component is
+///   required when multiple trees with the same tag contain the same widget paths (e.g. in multi-window apps).
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct InUiTree(pub Entity);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Helper system param for accessing all `bevy_lunex::UiTree<Ui>`s with a given tag.
+#[derive(SystemParam)]
+pub struct UiTrees<'w, 's, U: LunexUi>
+{
+    trees: Query<'w, 's, UiTreeInfo<U>>,
+}
+
+impl<'w, 's, U: LunexUi> UiTrees<'w, 's, U>
+{
+    /// Check if there are no trees.
+    pub fn is_empty(&self) -> bool
+    {
+        self.trees.is_empty()
+    }
+
+    /// Get a tree.
+    pub fn get(&self, tree: Entity) -> Option<&UiTree<U>>
+    {
+        self.trees.get(tree).ok().map(|(_, ui, _, _, _)| ui)
+    }
+
+    /// Get the window a tree is associated with.
+    pub fn window(&self, tree: Entity) -> Option<Entity>
+    {
+        let Ok((_, _, is_window, tree_window, _)) = self.trees.get(tree) else { return None; };
+        if is_window { return Some(tree); }
+        tree_window.map(|tree_window| tree_window.0)
+    }
+
+    /// Get the trees that can be interacted with from a window, ordered from top-most to bottom-most.
+    /// - Pass `None` to get only trees not associated with any window.
+    pub fn in_window(&self, window: Option<Entity>) -> Vec<(Entity, &UiTree<U>)>
+    {
+        let mut trees: Vec<((i32, Entity), &UiTree<U>)> = self.trees
+            .iter()
+            .filter(|(entity, _, _, _, _)|
+                {
+                    let tree_window = self.window(*entity);
+                    tree_window.is_none() || tree_window == window
+                }
+            )
+            .map(|(entity, ui, _, _, layer)| ((layer.map(|l| l.0).unwrap_or_default(), entity), ui))
+            .collect();
+        trees.sort_unstable_by(|(a, _), (b, _)| b.cmp(a));
+        trees.into_iter().map(|((_, entity), ui)| (entity, ui)).collect()
+    }
+
+    /// Check if `upper` occludes `lower` (the trees can be seen in the same window, and `upper` is on top).
+    pub fn occludes(&self, upper: Entity, lower: Entity) -> bool
+    {
+        let Ok((_, _, _, _, upper_layer)) = self.trees.get(upper) else { return false; };
+        let Ok((_, _, _, _, lower_layer)) = self.trees.get(lower) else { return false; };
+
+        let (upper_window, lower_window) = (self.window(upper), self.window(lower));
+        if upper_window.is_some() && lower_window.is_some() && upper_window != lower_window { return false; }
+
+        let upper_key = (upper_layer.map(|l| l.0).unwrap_or_default(), upper);
+        let lower_key = (lower_layer.map(|l| l.0).unwrap_or_default(), lower);
+        upper_key > lower_key
+    }
+
+    /// Find the tree a widget belongs to.
+    /// - If `in_tree` is set, that tree is returned if it contains the widget.
+    /// - Otherwise returns the first tree that contains the widget.
+    pub fn find(&self, in_tree: Option<&InUiTree>, widget: &Widget) -> Option<(Entity, &UiTree<U>)>
+    {
+        if let Some(in_tree) = in_tree
+        {
+            let ui = self.get(in_tree.0)?;
+            widget.fetch(ui).ok()?;
+            return Some((in_tree.0, ui));
+        }
+
+        self.trees
+            .iter()
+            .find(|(_, ui, _, _, _)| widget.fetch(ui).is_ok())
+            .map(|(entity, ui, _, _, _)| (entity, ui))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Check if an entity with an optional [`InUiTree`] component may belong to `tree`.
+pub(crate) fn belongs_to_tree(in_tree: Option<&InUiTree>, tree: Entity) -> bool
+{
+    match in_tree
+    {
+        Some(in_tree) => in_tree.0 == tree,
+        None          => true,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
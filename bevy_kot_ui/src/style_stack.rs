@@ -30,6 +30,32 @@ impl<Ui: LunexUi> DerefMut for StyleStackRes<Ui> { fn deref_mut(&mut self) -> &m
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Error that can occur when editing styles in a [`StyleStack`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StyleError
+{
+    /// There is no entry for the style.
+    Missing(&'static str),
+    /// The style was hidden with [`StyleStack::hide()`].
+    Hidden(&'static str),
+}
+
+impl std::fmt::Display for StyleError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            StyleError::Missing(name) => write!(f, "style {name} is missing"),
+            StyleError::Hidden(name)  => write!(f, "style {name} is hidden"),
+        }
+    }
+}
+
+impl std::error::Error for StyleError {}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Manages a stack of styles.
 ///
 /// The 'root stack frame' is implicit and cannot be popped. Styles in the root frame are permanent unless you call
@@ -135,12 +161,18 @@ impl StyleStack
     /// Edit a specific style and insert the edited version into the current style frame.
     ///
     /// Returns `Err` if there is no style entry or if the style was hidden with [`StyleStack::hide()`].
-    pub fn edit<S: Style + Clone>(&mut self, editor: impl FnOnce(&mut S)) -> Result<Arc<S>, ()>
+    pub fn edit<S: Style + Clone>(&mut self, editor: impl FnOnce(&mut S)) -> Result<Arc<S>, StyleError>
     {
-        let Some(mut style) = self.get_clone::<S>() else { return Err(()); };
+        let Some(mut style) = self.get_clone::<S>()
+        else
+        {
+            let name = std::any::type_name::<S>();
+            let is_hidden = self.styles.get(&TypeId::of::<S>()).is_some_and(|stack| !stack.is_empty());
+            return Err(if is_hidden { StyleError::Hidden(name) } else { StyleError::Missing(name) });
+        };
         (editor)(&mut style);
         self.add(style);
-        self.get::<S>().ok_or(())
+        Ok(self.get::<S>().expect("edited style should be on the style stack"))
     }
 
     fn insert(&mut self, type_id: TypeId, style: Arc<dyn Any + Send + Sync + 'static>)
@@ -28,7 +28,8 @@ pub struct UiBuilder<'w, 's, Ui: LunexUi>
     pub style_stack  : ResMut<'w, StyleStackRes<Ui>>,
     pub despawner    : Res<'w, AutoDespawner>,
 
-    ui: Query<'w, 's, &'static mut UiTree<Ui>>,  //todo: what about trees in different windows?
+    ui          : Query<'w, 's, &'static mut UiTree<Ui>>,
+    target_tree : Local<'s, Option<Entity>>,
 }
 
 impl<'w, 's, Ui: LunexUi> UiBuilder<'w, 's, Ui>
@@ -39,10 +40,30 @@ impl<'w, 's, Ui: LunexUi> UiBuilder<'w, 's, Ui>
         self.rcommands.commands()
     }
 
+    /// Set the `UiTree<Ui>` entity the builder should use.
+    /// - Only needed if there are multiple trees with the `Ui` tag (e.g. one per window).
+    /// - The target persists between runs of the system that owns the builder.
+    pub fn set_tree(&mut self, tree: Entity)
+    {
+        *self.target_tree = Some(tree);
+    }
+
+    /// Get the builder's target `UiTree<Ui>` entity, if one was set with [`UiBuilder::set_tree()`].
+    pub fn tree_entity(&self) -> Option<Entity>
+    {
+        *self.target_tree
+    }
+
     /// Get a reference to the builder's associated `UiTree<Ui>`.
+    ///
+    /// Panics if no target tree is set and there is not exactly one tree, or if the target tree doesn't exist.
     pub fn tree<'a>(&'a mut self) -> &'a mut UiTree<Ui>
     {
-        self.ui.single_mut().into_inner()
+        match *self.target_tree
+        {
+            Some(tree) => self.ui.get_mut(tree).expect("ui builder target tree is missing").into_inner(),
+            None       => self.ui.single_mut().into_inner(),
+        }
     }
 
     /// Create a new UI tree content division.
@@ -114,8 +135,8 @@ impl<'w, 's, Ui: LunexUi> UiBuilder<'w, 's, Ui>
 
     /// Edit a style on the style stack and place the updated copy in the current style frame.
     ///
-    /// Returns `Err` if the style doesn't exist.
-    pub fn edit_style<S: Style + Clone>(&mut self, editor: impl FnOnce(&mut S)) -> Result<Arc<S>, ()>
+    /// Returns `Err` if the style doesn't exist or is hidden.
+    pub fn edit_style<S: Style + Clone>(&mut self, editor: impl FnOnce(&mut S)) -> Result<Arc<S>, StyleError>
     {
         self.style_stack.edit::<S>(editor)
     }
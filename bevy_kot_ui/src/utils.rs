@@ -78,28 +78,47 @@ pub fn relative_widget<Ui: LunexUi>(
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Toggle between two sets of widgets.
-//todo: handle multiple uis (pass in UI entity)
-pub fn toggle_ui_visibility<Ui: LunexUi, const ON: usize, const OFF: usize>(
-    In((_, on_widgets, off_widgets)) : In<(Ui, [Widget; ON], [Widget; OFF])>,
-    mut uis                          : Query<&mut UiTree<Ui>>,
-){
-    // get target ui
-    let Ok(mut ui) = uis.get_single_mut() else { tracing::error!("multiple uis detected in toggle ui vis"); return; };
-
+/// Set the visibility of two sets of widgets in a UI tree.
+fn set_widget_visibility<Ui: LunexUi>(ui: &mut UiTree<Ui>, on_widgets: &[Widget], off_widgets: &[Widget])
+{
     // set widget visibility: on
     for on_widget in on_widgets
     {
-        let Ok(on_widget_branch) = on_widget.fetch_mut(&mut ui) else { continue; };
+        let Ok(on_widget_branch) = on_widget.fetch_mut(ui) else { continue; };
         on_widget_branch.set_visibility(true);
     }
 
     // set widget visibility: off
     for off_widget in off_widgets
     {
-        let Ok(off_widget_branch) = off_widget.fetch_mut(&mut ui) else { continue; };
+        let Ok(off_widget_branch) = off_widget.fetch_mut(ui) else { continue; };
         off_widget_branch.set_visibility(false);
     }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+/// Toggle between two sets of widgets.
+/// - Applies to all UI trees with the `Ui` tag. Use [`toggle_ui_tree_visibility()`] to target a specific tree.
+pub fn toggle_ui_visibility<Ui: LunexUi, const ON: usize, const OFF: usize>(
+    In((_, on_widgets, off_widgets)) : In<(Ui, [Widget; ON], [Widget; OFF])>,
+    mut uis                          : Query<&mut UiTree<Ui>>,
+){
+    for mut ui in uis.iter_mut()
+    {
+        set_widget_visibility(&mut ui, &on_widgets, &off_widgets);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Toggle between two sets of widgets in a specific UI tree.
+pub fn toggle_ui_tree_visibility<Ui: LunexUi, const ON: usize, const OFF: usize>(
+    In((tree, on_widgets, off_widgets)) : In<(Entity, [Widget; ON], [Widget; OFF])>,
+    mut uis                             : Query<&mut UiTree<Ui>>,
+){
+    let Ok(mut ui) = uis.get_mut(tree) else { tracing::error!("ui tree {tree:?} missing in toggle ui vis"); return; };
+    set_widget_visibility(&mut ui, &on_widgets, &off_widgets);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
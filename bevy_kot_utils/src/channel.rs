@@ -9,12 +9,14 @@ use bevy::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Multi-producer sender.
-#[derive(Component, Resource, Clone, Debug)]
+#[derive(Component, Resource, Debug)]
 pub struct Sender<T>
 {
     sender: crossbeam::channel::Sender<T>
 }
 
+impl<T> Clone for Sender<T> { fn clone(&self) -> Self { Self{ sender: self.sender.clone() } } }
+
 impl<T> Sender<T>
 {
     fn new(sender: crossbeam::channel::Sender<T>) -> Sender<T>
@@ -31,12 +33,14 @@ impl<T> Sender<T>
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Multi-consumer receiver.
-#[derive(Component, Resource, Clone, Debug)]
+#[derive(Component, Resource, Debug)]
 pub struct Receiver<T>
 {
     receiver: crossbeam::channel::Receiver<T>
 }
 
+impl<T> Clone for Receiver<T> { fn clone(&self) -> Self { Self{ receiver: self.receiver.clone() } } }
+
 impl<T> Receiver<T>
 {
     fn new(receiver: crossbeam::channel::Receiver<T>) -> Receiver<T>
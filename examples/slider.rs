@@ -5,7 +5,6 @@ use bevy_kot::prelude::*;
 use bevy::prelude::*;
 use bevy::window::{PrimaryWindow, WindowTheme};
 use bevy_lunex::prelude::*;
-use bevy_lunex_core::Size;
 
 //standard shortcuts
 
@@ -13,90 +12,31 @@ use bevy_lunex_core::Size;
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-/// WARNING: Becomes invalid if the window is resized (which should not happen during a slider drag since the cursor
-///          is occupied).
-#[derive(Component, Debug)]
-struct SliderDragState
-{
-    right_edge_min: f32,
-    right_edge_max: f32,
-    drag_start_x: f32,
-    widget_start_x: f32,
-    widget_start_displacement_1_x: f32,
-    widget_start_displacement_2_x: f32,
-}
-
-//-------------------------------------------------------------------------------------------------------------------
-//-------------------------------------------------------------------------------------------------------------------
-
-fn initialize_slider_drag(
-    In((
-        entity,
-        (right_edge_min_rel, right_edge_max_rel)
-    ))           : In<(Entity, (f32, f32))>,
-    mut commands : Commands,
-    cursor_pos   : CursorPos<MainMouseCursor>,
-    widgets      : Query<&Widget>,
-    ui           : Query<(&UiTree<MainUi>, &Size)>,  //todo: InFocusedWindow
-){
-    // slider entity
-    let Some(mut entity_commands) = commands.get_entity(entity) else { return; };
-    let Ok(slider_widget) = widgets.get(entity) else { return; };
-
-    // widget start position
-    let Ok((ui, dims)) = ui.get_single() else { return; };
-    let Ok(widget_branch) = slider_widget.fetch(&ui) else { return; };
-    let widget_start_pos = widget_branch.get_container().get_position().get_pos(Vec2::default());
-    let LayoutPackage::Relative(ref layout) = widget_branch.get_container().get_layout() else { return; };
-    let widget_start_displacement_1_x = layout.absolute_1.x;
-    let widget_start_displacement_2_x = layout.absolute_2.x;
-
-    // cursor start position
-    let Some(cpos_screen) = cursor_pos.get_screen() else { return; };
-
-    // denormalize edge min/max to absolute coordinates
-    let right_edge_min = right_edge_min_rel * dims.width / 100.0;
-    let right_edge_max = right_edge_max_rel * dims.width / 100.0;
-
-    // overwrite any existing drag state
-    entity_commands.insert(
-            SliderDragState{
-                    right_edge_min,
-                    right_edge_max,
-                    drag_start_x   : cpos_screen.x,
-                    widget_start_x : -widget_start_pos.x,
-                    widget_start_displacement_1_x,
-                    widget_start_displacement_2_x
-                }
-        );
-}
-
-//-------------------------------------------------------------------------------------------------------------------
-//-------------------------------------------------------------------------------------------------------------------
-
+/// Move the slider button so it follows the cursor along the slider bar.
+/// - The button is positioned in relative coordinates, so the slider is unaffected by window resizing mid-drag.
 fn drag_slider(
-    In(entity) : In<Entity>,
-    cursor_pos : CursorPos<MainMouseCursor>,
-    mut ui     : Query<&mut UiTree<MainUi>>,  //todo: InFocusedWindow
-    slider     : Query<(&Widget, &SliderDragState)>,
+    In((
+        info,
+        button,
+        slider_bar,
+        (button_edge_right, slider_width, button_width)
+    ))     : In<(DragInfo, Widget, Widget, (f32, f32, f32))>,
+    mut ui : Query<&mut UiTree<MainUi>>,
 ){
-    // slider
-    let Ok((slider_widget, drag_state)) = slider.get(entity) else { return; };
-
-    // ui
-    let Ok(mut ui) = ui.get_single_mut() else { return; };
+    // ui containing the slider
+    let Ok(mut ui) = ui.get_mut(info.tree()) else { return; };
 
-    // get new position for widget
-    let Some(cpos_screen) = cursor_pos.get_screen() else { return; };
-    let drag_diff = cpos_screen.x - drag_state.drag_start_x;
-    let new_widget_x = (drag_state.widget_start_x + drag_diff).max(drag_state.right_edge_min).min(drag_state.right_edge_max);
-    let new_widget_x_diff = new_widget_x - drag_state.widget_start_x;
+    // get new position for widget along the slider bar (keep the button where it was grabbed)
+    let Some(cursor_rel) = info.position_in(&ui, &slider_bar) else { return; };
+    let grab_offset = (info.grab().x - 0.5) * button_width / slider_width;
+    let slider_value = (cursor_rel.x - grab_offset).clamp(0.0, 1.0);
+    let new_widget_x = button_edge_right + slider_value * slider_width;
 
     // update widget position
-    let Ok(widget_branch) = slider_widget.fetch_mut(&mut ui) else { return; };
+    let Ok(widget_branch) = button.fetch_mut(&mut ui) else { return; };
     let LayoutPackage::Relative(ref mut layout) = widget_branch.get_container_mut().get_layout_mut() else { return; };
-    layout.absolute_1.x = drag_state.widget_start_displacement_1_x + new_widget_x_diff;
-    layout.absolute_2.x = drag_state.widget_start_displacement_2_x + new_widget_x_diff;
+    layout.relative_1.x = new_widget_x;
+    layout.relative_2.x = new_widget_x + button_width;
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -134,10 +74,15 @@ fn build_ui(mut ui: UiBuilder<MainUi>)
     add_button_rect(&mut ui, &slider_bar, Color::BLACK);
 
     // button widget
-    let button_edge_right = 25.0;
-    let button_edge_right_max = button_edge_right + slider_width;
-    let button_x_range_rel = (button_edge_right, button_edge_right_max);
-    let button = relative_widget(ui.tree(), root.end("button"), (button_edge_right, 35.0), (45., 55.));
+    let button_width = 10.0;
+    let button_edge_right = slider_x_left - button_width / 2.0;
+    let button_geometry = (button_edge_right, slider_width, button_width);
+    let button = relative_widget(
+            ui.tree(),
+            root.end("button"),
+            (button_edge_right, button_edge_right + button_width),
+            (45., 55.)
+        );
 
     // default button image tied to button
     let default_widget = make_overlay(ui.tree(), &button, "default", true);
@@ -155,9 +100,9 @@ fn build_ui(mut ui: UiBuilder<MainUi>)
     // button entity
     let despawner = ui.despawner.clone();
     let mut entity_commands = ui.commands().spawn_empty();
-    let entity = entity_commands.id();
 
-    // button as interactive element
+    // button as draggable interactive element
+    let button_dragged = button.clone();
     InteractiveElementBuilder::new()
         .with_default_widget(default_widget)
         .with_pressed_widget(pressed_widget)
@@ -165,8 +110,13 @@ fn build_ui(mut ui: UiBuilder<MainUi>)
         .press_on_click()
         .unpress_on_unclick_home_or_away()
         .abort_press_if_obstructed()
-        .on_startpress(move |world: &mut World| syscall(world, (entity, button_x_range_rel), initialize_slider_drag))
-        .on_press_home(move |world: &mut World| syscall(world, entity, drag_slider))
+        .on_drag(
+            move |In(info): In<DragInfo>, world: &mut World|
+            {
+                let input = (info, button_dragged.clone(), slider_bar.clone(), button_geometry);
+                syscall(world, input, drag_slider);
+            }
+        )
         .build::<MouseLButtonMain>(&despawner, &mut entity_commands, button.clone())
         .unwrap();
     entity_commands.insert(UiInteractionBarrier::<MainUi>::default());
@@ -124,3 +124,227 @@ fn auto_despawn_multiple()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct OtherComponent;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn auto_despawn_recursive()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn();
+
+    let parent = app.world.spawn(TestComponent).id();
+    let child = app.world.spawn(TestComponent).id();
+    let grandchild = app.world.spawn(TestComponent).id();
+    app.world.entity_mut(parent).add_child(child);
+    app.world.entity_mut(child).add_child(grandchild);
+
+    let handle = app.world.resource::<AutoDespawner>().prepare_recursive(parent);
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 3);
+
+    // the entity's descendants are despawned with it
+    std::mem::drop(handle);
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn auto_despawn_remove_components()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn();
+
+    let entity = app.world.spawn((TestComponent, OtherComponent)).id();
+    let handle = app.world.resource::<AutoDespawner>().prepare_remove::<TestComponent>(entity);
+
+    // only the bundle is removed
+    std::mem::drop(handle);
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 0);
+    assert!(app.world.get::<OtherComponent>(entity).is_some());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn auto_despawn_custom_cleanup()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn();
+
+    let entity = app.world.spawn(TestComponent).id();
+    let other = app.world.spawn(OtherComponent).id();
+    let handle = app.world.resource::<AutoDespawner>().prepare_with(entity,
+            move |In(entity): In<Entity>, mut commands: Commands|
+            {
+                commands.entity(entity).despawn();
+                commands.entity(other).despawn();
+            }
+        );
+
+    // the cleanup system runs with the entity as input
+    std::mem::drop(handle);
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 0);
+    assert!(app.world.get_entity(other).is_none());
+
+    // cleanup doesn't run for entities that were already despawned
+    let entity = app.world.spawn(TestComponent).id();
+    let handle = app.world.resource::<AutoDespawner>().prepare_with(entity,
+            |In(_): In<Entity>, mut commands: Commands| { commands.spawn(OtherComponent); }
+        );
+    app.world.despawn(entity);
+    std::mem::drop(handle);
+    app.update();
+    assert_eq!(app.world.query::<&OtherComponent>().iter(&app.world).count(), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn auto_despawn_flush()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn();
+
+    let handle = syscall(&mut app.world, (), spawn_test_entity);
+    flush_auto_despawns(&mut app.world);
+    assert_eq!(syscall(&mut app.world, (), count_entities), 1);
+
+    // dropped signals are handled immediately
+    std::mem::drop(handle);
+    flush_auto_despawns(&mut app.world);
+    assert_eq!(syscall(&mut app.world, (), count_entities), 0);
+
+    // flushing without auto despawning does nothing
+    flush_auto_despawns(&mut World::new());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct CountInUpdate(Option<usize>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn auto_despawn_custom_schedule()
+{
+    let mut app = App::new();
+    app.add_auto_despawn_schedule(PreUpdate)
+        .add_auto_despawn_schedule(PreUpdate)
+        .init_resource::<CountInUpdate>()
+        .add_systems(Update,
+            |count: Query<(), With<TestComponent>>, mut count_in_update: ResMut<CountInUpdate>|
+            {
+                count_in_update.0 = Some(count.iter().count());
+            }
+        );
+
+    let handle = syscall(&mut app.world, (), spawn_test_entity);
+    app.update();
+    assert_eq!(app.world.resource::<CountInUpdate>().0, Some(1));
+
+    // the entity is despawned before `Update` runs
+    std::mem::drop(handle);
+    app.update();
+    assert_eq!(app.world.resource::<CountInUpdate>().0, Some(0));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn auto_despawn_replace_schedule()
+{
+    let mut app = App::new();
+    app.set_auto_despawn_schedule(PreUpdate);
+
+    // dropped signals are not handled in `Last`
+    let handle = syscall(&mut app.world, (), spawn_test_entity);
+    std::mem::drop(handle);
+    app.world.run_schedule(Last);
+    assert_eq!(syscall(&mut app.world, (), count_entities), 1);
+
+    app.world.run_schedule(PreUpdate);
+    assert_eq!(syscall(&mut app.world, (), count_entities), 0);
+
+    // `Last` can be restored
+    app.set_auto_despawn_schedule(Last);
+    let handle = syscall(&mut app.world, (), spawn_test_entity);
+    std::mem::drop(handle);
+    app.world.run_schedule(PreUpdate);
+    assert_eq!(syscall(&mut app.world, (), count_entities), 1);
+
+    app.world.run_schedule(Last);
+    assert_eq!(syscall(&mut app.world, (), count_entities), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn auto_despawn_weak_signal()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn();
+
+    let handle = syscall(&mut app.world, (), spawn_test_entity);
+    let weak = handle.downgrade();
+    assert_eq!(weak.entity(), handle.entity());
+    assert!(weak.is_alive());
+    assert_eq!(weak.strong_count(), 1);
+
+    // upgrading keeps the entity alive
+    let upgraded = weak.upgrade().unwrap();
+    assert_eq!(handle.strong_count(), 2);
+    std::mem::drop(handle);
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 1);
+
+    // weak signals don't keep the entity alive
+    std::mem::drop(upgraded);
+    assert!(!weak.is_alive());
+    assert!(weak.upgrade().is_none());
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn auto_despawn_diagnostics()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn()
+        .log_auto_despawn_signals(true);
+
+    let handle1 = syscall(&mut app.world, (), spawn_test_entity);
+    let handle2 = syscall(&mut app.world, (), spawn_test_entity);
+    let _clone = handle2.clone();
+    assert_eq!(app.world.resource::<AutoDespawner>().outstanding_signals(), 2);
+
+    app.update();
+    assert_eq!(app.world.resource::<AutoDespawnDiagnostics>().outstanding_signals, 2);
+    assert_eq!(app.world.resource::<AutoDespawnDiagnostics>().last_flush_despawns, 0);
+
+    // dropped signals are pending until the next flush
+    std::mem::drop(handle1);
+    std::mem::drop(handle2);
+    assert_eq!(app.world.resource::<AutoDespawner>().outstanding_signals(), 1);
+    assert_eq!(app.world.resource::<AutoDespawner>().pending_despawns(), 1);
+
+    app.update();
+    assert_eq!(app.world.resource::<AutoDespawner>().pending_despawns(), 0);
+    let diagnostics = *app.world.resource::<AutoDespawnDiagnostics>();
+    assert_eq!(diagnostics.outstanding_signals, 1);
+    assert_eq!(diagnostics.last_flush_despawns, 1);
+    assert_eq!(diagnostics.total_despawns, 1);
+    assert_eq!(syscall(&mut app.world, (), count_entities), 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,286 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+struct OnClick;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct Calls(Vec<u32>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn push_call(id: u32) -> impl Fn(&mut World) + Send + Sync + 'static
+{
+    move |world: &mut World| world.resource_mut::<Calls>().0.push(id)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn callback_list_invokes_handlers_in_order()
+{
+    let mut world = World::new();
+    world.init_resource::<Calls>();
+    let entity = world.spawn_empty().id();
+
+    let _ = add_callback::<OnClick>(&mut world, entity, push_call(1)).unwrap();
+    let second = add_callback::<OnClick>(&mut world, entity, push_call(2)).unwrap();
+    let _ = add_callback::<OnClick>(&mut world, entity, push_call(3)).unwrap();
+
+    assert_eq!(try_callbacks::<OnClick>(&mut world, entity), 3);
+    assert_eq!(world.resource::<Calls>().0, vec![1, 2, 3]);
+
+    // remove a handler by id
+    assert!(remove_callback::<OnClick>(&mut world, entity, second));
+    assert!(!remove_callback::<OnClick>(&mut world, entity, second));
+    world.resource_mut::<Calls>().0.clear();
+    assert_eq!(try_callbacks::<OnClick>(&mut world, entity), 2);
+    assert_eq!(world.resource::<Calls>().0, vec![1, 3]);
+
+    // missing lists and entities
+    let other = world.spawn_empty().id();
+    assert_eq!(try_callbacks::<OnClick>(&mut world, other), 0);
+    world.despawn(other);
+    assert_eq!(add_callback::<OnClick>(&mut world, other, push_call(4)), None);
+    assert_eq!(try_callbacks::<OnClick>(&mut world, other), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn callback_list_handlers_can_edit_list()
+{
+    let mut world = World::new();
+    world.init_resource::<Calls>();
+    let entity = world.spawn_empty().id();
+
+    // a handler that removes itself and adds another handler
+    let id = CallbackId::new();
+    world.entity_mut(entity).insert(CallbackList::<OnClick>::default());
+    world.get_mut::<CallbackList<OnClick>>(entity).unwrap().add_with_id(id,
+            move |world: &mut World|
+            {
+                world.resource_mut::<Calls>().0.push(1);
+                assert!(remove_callback::<OnClick>(world, entity, id));
+                let _ = add_callback::<OnClick>(world, entity, push_call(2));
+            }
+        );
+
+    // edits take effect on the next invocation
+    assert_eq!(try_callbacks::<OnClick>(&mut world, entity), 1);
+    assert_eq!(try_callbacks::<OnClick>(&mut world, entity), 1);
+    assert_eq!(world.resource::<Calls>().0, vec![1, 2]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn callback_list_with_payload()
+{
+    let mut world = World::new();
+    world.init_resource::<Calls>();
+    let entity = world.spawn_empty().id();
+
+    let _ = add_callback_with::<OnClick, u32>(&mut world, entity, |world, value| world.resource_mut::<Calls>().0.push(value));
+    let double = add_callback_with::<OnClick, u32>(&mut world, entity,
+            |world, value| world.resource_mut::<Calls>().0.push(value * 2)
+        ).unwrap();
+
+    assert_eq!(try_callbacks_with::<OnClick, u32>(&mut world, entity, 5), 2);
+    assert_eq!(world.resource::<Calls>().0, vec![5, 10]);
+
+    assert!(remove_callback_with::<OnClick, u32>(&mut world, entity, double));
+    assert_eq!(try_callbacks_with::<OnClick, u32>(&mut world, entity, 1), 1);
+    assert_eq!(world.resource::<Calls>().0, vec![5, 10, 1]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn callback_list_commands()
+{
+    let mut world = World::new();
+    world.init_resource::<Calls>();
+    let entity = world.spawn_empty().id();
+
+    let id = syscall(&mut world, (),
+            move |mut commands: Commands| -> CallbackId
+            {
+                commands.add_callback::<OnClick>(entity, push_call(1));
+                commands.add_callback::<OnClick>(entity, push_call(2))
+            }
+        );
+    assert_eq!(world.get::<CallbackList<OnClick>>(entity).unwrap().len(), 2);
+    assert!(world.get::<CallbackList<OnClick>>(entity).unwrap().contains(id));
+
+    syscall(&mut world, (), move |mut commands: Commands| commands.remove_callback::<OnClick>(entity, id));
+    assert_eq!(try_callbacks::<OnClick>(&mut world, entity), 1);
+    assert_eq!(world.resource::<Calls>().0, vec![1]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn callback_from_system()
+{
+    let mut world = World::new();
+    world.init_resource::<Calls>();
+
+    // the system receives the owning entity and keeps its local state
+    let callback = Callback::<OnClick>::from_system(
+            |In(entity): In<Entity>, mut calls: ResMut<Calls>, mut count: Local<u32>|
+            {
+                *count += 1;
+                calls.0.push(entity.index() * 100 + *count);
+            }
+        );
+    let entity = world.spawn(callback).id();
+
+    assert!(try_callback::<OnClick>(&mut world, entity));
+    assert!(try_callback::<OnClick>(&mut world, entity));
+    assert_eq!(world.resource::<Calls>().0, vec![entity.index() * 100 + 1, entity.index() * 100 + 2]);
+
+    // closure-based callbacks still work
+    let other = world.spawn(Callback::<OnClick>::new(push_call(7))).id();
+    assert!(try_callback::<OnClick>(&mut world, other));
+    assert_eq!(world.resource::<Calls>().0.last(), Some(&7));
+
+    // commands made with `call_on` pass the owning entity to the system
+    let callback = world.get::<Callback<OnClick>>(entity).unwrap().clone();
+    assert!(callback.is_system());
+    syscall(&mut world, (), move |mut commands: Commands| commands.add(callback.call_on(entity)));
+    assert_eq!(world.resource::<Calls>().0.last(), Some(&(entity.index() * 100 + 3)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn callback_with_from_system()
+{
+    let mut world = World::new();
+    world.init_resource::<Calls>();
+
+    // the system receives the call value and keeps its local state
+    let callback = CallbackWith::<OnClick, u32>::from_system(
+            |In(value): In<u32>, mut calls: ResMut<Calls>, mut count: Local<u32>|
+            {
+                *count += 1;
+                calls.0.push(value * 10 + *count);
+            }
+        );
+    let entity = world.spawn(callback.clone()).id();
+
+    assert!(try_callback_with::<OnClick, u32>(&mut world, entity, 5));
+    syscall(&mut world, (), move |mut commands: Commands| commands.add(callback.call_with(6)));
+    assert_eq!(world.resource::<Calls>().0, vec![51, 62]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn system_callback_removes_itself()
+{
+    let mut world = World::new();
+    let entity = world.spawn(
+            Callback::<OnClick>::from_system(
+                |In(entity): In<Entity>, mut commands: Commands|
+                {
+                    commands.entity(entity).remove::<Callback<OnClick>>();
+                }
+            )
+        ).id();
+
+    assert!(try_callback::<OnClick>(&mut world, entity));
+    assert!(world.get::<Callback<OnClick>>(entity).is_none());
+    assert!(!try_callback::<OnClick>(&mut world, entity));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource)]
+struct StopAtList(bool);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn callbacks_bubble_to_ancestors()
+{
+    let mut world = World::new();
+    world.init_resource::<Calls>();
+    world.insert_resource(StopAtList(false));
+
+    // root -> list -> middle (no handler) -> item
+    let root = world.spawn(Callback::<OnClick>::new(push_call(1))).id();
+    let list = world.spawn(
+            Callback::<OnClick>::new(
+                |world: &mut World|
+                {
+                    let bubble = *world.resource::<CallbackBubble>();
+                    assert_ne!(bubble.target(), bubble.current());
+                    world.resource_mut::<Calls>().0.push(2);
+                    if world.resource::<StopAtList>().0 { stop_callback_propagation(world); }
+                }
+            )
+        ).id();
+    let middle = world.spawn_empty().id();
+    let item = world.spawn(Callback::<OnClick>::new(push_call(3))).id();
+    world.entity_mut(root).add_child(list);
+    world.entity_mut(list).add_child(middle);
+    world.entity_mut(middle).add_child(item);
+
+    // handlers run from the target up to the root
+    assert_eq!(bubble_callback::<OnClick>(&mut world, item), 3);
+    assert_eq!(world.resource::<Calls>().0, vec![3, 2, 1]);
+    assert!(world.get_resource::<CallbackBubble>().is_none());
+
+    // propagation can be stopped
+    world.resource_mut::<Calls>().0.clear();
+    world.resource_mut::<StopAtList>().0 = true;
+    assert_eq!(bubble_callback::<OnClick>(&mut world, item), 2);
+    assert_eq!(world.resource::<Calls>().0, vec![3, 2]);
+
+    // bubbling from an entity without handlers invokes its ancestors
+    world.resource_mut::<Calls>().0.clear();
+    assert_eq!(bubble_callback::<OnClick>(&mut world, middle), 1);
+    assert_eq!(world.resource::<Calls>().0, vec![2]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn bubbling_system_callbacks()
+{
+    let mut world = World::new();
+    world.init_resource::<Calls>();
+
+    // a system callback handles clicks of its children and stops propagation
+    let root = world.spawn(Callback::<OnClick>::new(push_call(1))).id();
+    let list = world.spawn(
+            Callback::<OnClick>::from_system(
+                |In(_): In<Entity>, mut bubble: ResMut<CallbackBubble>, mut calls: ResMut<Calls>|
+                {
+                    calls.0.push(bubble.target().index());
+                    bubble.stop_propagation();
+                }
+            )
+        ).id();
+    let item = world.spawn_empty().id();
+    world.entity_mut(root).add_child(list);
+    world.entity_mut(list).add_child(item);
+
+    assert_eq!(bubble_callback::<OnClick>(&mut world, item), 1);
+    assert_eq!(world.resource::<Calls>().0, vec![item.index()]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
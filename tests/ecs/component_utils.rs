@@ -0,0 +1,239 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Debug, Eq, PartialEq)]
+struct A(usize);
+
+#[derive(Component, Debug, Eq, PartialEq)]
+struct B(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn changed_a(query: Query<(), Changed<A>>) -> usize
+{
+    query.iter().count()
+}
+
+fn changed_b(query: Query<(), Changed<B>>) -> usize
+{
+    query.iter().count()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn missing_entity(world: &mut World) -> Entity
+{
+    let entity = world.spawn_empty().id();
+    world.despawn(entity);
+    entity
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn insert_bundle()
+{
+    let mut world = World::new();
+    let entity = world.spawn(A(0)).id();
+    let missing = missing_entity(&mut world);
+
+    // existing components are overwritten
+    assert!(try_insert_bundle(&mut world, entity, (A(1), B(1))));
+    assert_eq!(world.get::<A>(entity), Some(&A(1)));
+    assert_eq!(world.get::<B>(entity), Some(&B(1)));
+
+    // missing entity
+    assert!(!try_insert_bundle(&mut world, missing, (A(0), B(0))));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn remove_bundle()
+{
+    let mut world = World::new();
+    let entity = world.spawn((A(0), B(1))).id();
+    let partial = world.spawn(A(0)).id();
+    let missing = missing_entity(&mut world);
+
+    assert_eq!(try_remove_bundle::<(A, B)>(&mut world, entity), Some((A(0), B(1))));
+    assert_eq!(try_remove_bundle::<(A, B)>(&mut world, entity), None);
+
+    // nothing is removed if any component is missing
+    assert_eq!(try_remove_bundle::<(A, B)>(&mut world, partial), None);
+    assert_eq!(world.get::<A>(partial), Some(&A(0)));
+
+    // missing entity
+    assert_eq!(try_remove_bundle::<(A, B)>(&mut world, missing), None);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn set_bundle()
+{
+    let mut world = World::new();
+    let entity = world.spawn(A(0)).id();
+    let missing = missing_entity(&mut world);
+
+    // missing components cause the whole bundle to be inserted
+    assert!(try_set_bundle(&mut world, entity, (A(1), B(1))));
+    assert_eq!(world.get::<A>(entity), Some(&A(1)));
+    assert_eq!(world.get::<B>(entity), Some(&B(1)));
+
+    // unchanged values
+    let _ = syscall(&mut world, (), changed_a);
+    let _ = syscall(&mut world, (), changed_b);
+    assert!(!try_set_bundle(&mut world, entity, (A(1), B(1))));
+    assert_eq!(syscall(&mut world, (), changed_a), 0);
+    assert_eq!(syscall(&mut world, (), changed_b), 0);
+
+    // only modified components are changed
+    assert!(try_set_bundle(&mut world, entity, (A(2), B(1))));
+    assert_eq!(syscall(&mut world, (), changed_a), 1);
+    assert_eq!(syscall(&mut world, (), changed_b), 0);
+    assert_eq!(world.get::<A>(entity), Some(&A(2)));
+
+    // missing entity
+    assert!(!try_set_bundle(&mut world, missing, (A(0), B(0))));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn update_bundle_if_different()
+{
+    let mut world = World::new();
+    let entity = world.spawn((A(0), B(0))).id();
+    let partial = world.spawn(A(0)).id();
+    let missing = missing_entity(&mut world);
+
+    // unchanged values
+    let _ = syscall(&mut world, (), changed_a);
+    let _ = syscall(&mut world, (), changed_b);
+    assert!(!try_update_bundle_if_different(&mut world, entity, (A(0), B(0))));
+
+    // only modified components are changed
+    assert!(try_update_bundle_if_different(&mut world, entity, (A(0), B(1))));
+    assert_eq!(syscall(&mut world, (), changed_a), 0);
+    assert_eq!(syscall(&mut world, (), changed_b), 1);
+    assert_eq!(world.get::<B>(entity), Some(&B(1)));
+
+    // nothing is updated if any component is missing
+    assert!(!try_update_bundle_if_different(&mut world, partial, (A(1), B(1))));
+    assert_eq!(world.get::<A>(partial), Some(&A(0)));
+    assert_eq!(world.get::<B>(partial), None);
+
+    // missing entity
+    assert!(!try_update_bundle_if_different(&mut world, missing, (A(0), B(0))));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn insert_bundle_batch()
+{
+    let mut world = World::new();
+    let entities: Vec<Entity> = (0..3).map(|i| world.spawn(A(i)).id()).collect();
+    let missing = missing_entity(&mut world);
+
+    // missing entities are skipped (and not spawned)
+    let batch = entities.iter().copied().chain([missing]).map(|e| (e, (A(5), B(5))));
+    assert_eq!(try_insert_bundle_batch(&mut world, batch), 3);
+    for entity in entities.iter()
+    {
+        assert_eq!(world.get::<A>(*entity), Some(&A(5)));
+        assert_eq!(world.get::<B>(*entity), Some(&B(5)));
+    }
+    assert!(world.get_entity(missing).is_none());
+
+    // empty batch
+    assert_eq!(try_insert_bundle_batch::<(A,)>(&mut world, []), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn set_component_batch()
+{
+    let mut world = World::new();
+    let with_b = world.spawn(B(0)).id();
+    let without_b = world.spawn(A(0)).id();
+    let missing = missing_entity(&mut world);
+
+    // updated, inserted, unchanged, and missing
+    let unchanged = world.spawn(B(1)).id();
+    let batch = [(with_b, B(2)), (without_b, B(2)), (unchanged, B(1)), (missing, B(2))];
+    assert_eq!(try_set_component_batch(&mut world, batch), 2);
+    assert_eq!(world.get::<B>(with_b), Some(&B(2)));
+    assert_eq!(world.get::<B>(without_b), Some(&B(2)));
+    assert_eq!(world.get::<A>(without_b), Some(&A(0)));
+    assert!(world.get_entity(missing).is_none());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn set_bundle_batch()
+{
+    let mut world = World::new();
+    let full = world.spawn((A(0), B(0))).id();
+    let partial = world.spawn(A(0)).id();
+    let unchanged = world.spawn((A(1), B(1))).id();
+    let missing = missing_entity(&mut world);
+
+    let _ = syscall(&mut world, (), changed_a);
+    let batch = [(full, (A(0), B(1))), (partial, (A(1), B(1))), (unchanged, (A(1), B(1))), (missing, (A(1), B(1)))];
+    assert_eq!(try_set_bundle_batch(&mut world, batch), 2);
+    assert_eq!(world.get::<B>(full), Some(&B(1)));
+    assert_eq!(world.get::<A>(partial), Some(&A(1)));
+    assert_eq!(world.get::<B>(partial), Some(&B(1)));
+
+    // only the inserted bundle changed `A`
+    assert_eq!(syscall(&mut world, (), changed_a), 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn component_utils_commands()
+{
+    let mut world = World::new();
+    let entities: Vec<Entity> = (0..3).map(|_| world.spawn(A(0)).id()).collect();
+    let missing = missing_entity(&mut world);
+
+    let entities_clone = entities.clone();
+    syscall(&mut world, (),
+        move |mut commands: Commands|
+        {
+            commands.try_insert_bundle(entities_clone[0], (B(1),));
+            commands.try_update_bundle_if_different(entities_clone[1], (A(0), B(1)));
+            commands.try_set_bundle_batch(entities_clone.iter().skip(1).map(|e| (*e, (A(2), B(2)))));
+            commands.try_update_component_if_different(entities_clone[0], A(3));
+            commands.try_set_component_batch([(entities_clone[0], B(4)), (missing, B(4))]);
+            commands.try_insert_bundle_batch([(entities_clone[2], (B(5),)), (missing, (B(5),))]);
+            commands.try_set_bundle(missing, (A(0), B(0)));
+        }
+    );
+
+    assert_eq!(world.get::<A>(entities[0]), Some(&A(3)));
+    assert_eq!(world.get::<B>(entities[0]), Some(&B(4)));
+    assert_eq!(world.get::<A>(entities[1]), Some(&A(2)));
+    assert_eq!(world.get::<B>(entities[1]), Some(&B(2)));
+    assert_eq!(world.get::<B>(entities[2]), Some(&B(5)));
+    assert!(world.get_entity(missing).is_none());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
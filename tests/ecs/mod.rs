@@ -1,3 +1,14 @@
 //test modules
 mod auto_despawn;
+mod callbacks;
+mod component_utils;
+mod ownership;
 mod react;
+mod react_forwarding;
+mod react_history;
+mod react_persistence;
+mod react_stats;
+mod react_testing;
+mod system_batch;
+mod system_callers;
+mod system_pipes;
@@ -0,0 +1,155 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct TestComponent;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn count_entities(num: Query<(), With<TestComponent>>) -> usize
+{
+    num.iter().count()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn ownership_chain_collapses()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn();
+
+    // root -> a -> b -> c
+    let root = app.world.spawn(TestComponent).id();
+    let owned = syscall(&mut app.world, (),
+        move |mut commands: Commands|
+        {
+            let a = commands.spawn_owned(root, TestComponent);
+            let b = commands.spawn_owned(a, TestComponent);
+            let c = commands.spawn_owned(b, TestComponent);
+            vec![a, b, c]
+        }
+    );
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 4);
+    assert!(app.world.get::<OwnedEntities>(root).unwrap().contains(owned[0]));
+    assert_eq!(app.world.get::<Owned>(owned[2]).unwrap().owner_count(), 1);
+
+    // despawning the root collapses the chain in one frame
+    app.world.despawn(root);
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn ownership_shared()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn();
+
+    let owner1 = app.world.spawn_empty().id();
+    let owner2 = app.world.spawn_empty().id();
+    let owned = app.world.spawn(TestComponent).id();
+    assert!(own_entity(&mut app.world, owner1, owned));
+    assert!(own_entity(&mut app.world, owner2, owned));
+    assert!(own_entity(&mut app.world, owner2, owned));
+    assert_eq!(app.world.get::<Owned>(owned).unwrap().owner_count(), 2);
+
+    // the entity stays alive while any owner remains
+    app.world.despawn(owner1);
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 1);
+
+    // releasing the last owner despawns the entity
+    syscall(&mut app.world, (), move |mut commands: Commands| commands.release_entity(owner2, owned));
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 0);
+    assert!(!release_entity(&mut app.world, owner2, owned));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn ownership_invalid()
+{
+    let mut app = App::new();
+    let owner = app.world.spawn_empty().id();
+    let owned = app.world.spawn(TestComponent).id();
+
+    // no despawner
+    assert!(!own_entity(&mut app.world, owner, owned));
+
+    // missing owner
+    app.setup_auto_despawn();
+    app.world.despawn(owner);
+    assert!(!own_entity(&mut app.world, owner, owned));
+
+    // entities spawned for a missing owner are despawned (only the unowned entity remains)
+    syscall(&mut app.world, (), move |mut commands: Commands| { commands.spawn_owned(owner, TestComponent); });
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn ownership_release_then_own_same_frame()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn();
+
+    let owner1 = app.world.spawn_empty().id();
+    let owner2 = app.world.spawn_empty().id();
+    let owned = app.world.spawn(TestComponent).id();
+    assert!(own_entity(&mut app.world, owner1, owned));
+    app.update();
+
+    // move the entity between owners in one frame
+    assert!(release_entity(&mut app.world, owner1, owned));
+    assert_eq!(app.world.resource::<AutoDespawner>().pending_despawns(), 1);
+    assert!(own_entity(&mut app.world, owner2, owned));
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 1);
+    assert_eq!(app.world.get::<Owned>(owned).unwrap().owner_count(), 1);
+
+    // the new owner still controls the entity's lifetime
+    app.world.despawn(owner2);
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn ownership_does_not_block_other_signals()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn();
+
+    let owner = app.world.spawn_empty().id();
+    let owned = app.world.spawn(TestComponent).id();
+    assert!(own_entity(&mut app.world, owner, owned));
+    let signal = app.world.resource::<AutoDespawner>().prepare(owned);
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 1);
+
+    // dropping an unrelated signal despawns the entity even though it is owned
+    std::mem::drop(signal);
+    app.update();
+    assert_eq!(syscall(&mut app.world, (), count_entities), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,140 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Default, Clone)]
+struct TestReactRes(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Clone)]
+struct TestEvent(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct TestReactRecorder(Vec<usize>);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_resource_mutation(mut rcommands: ReactCommands)
+{
+    rcommands.on(resource_mutation::<TestReactRes>(),
+            |mut recorder: ResMut<TestReactRecorder>, resource: ReactRes<TestReactRes>|
+            {
+                recorder.0.push(resource.0);
+            }
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_event(mut rcommands: ReactCommands)
+{
+    rcommands.on(event::<TestEvent>(),
+            |mut recorder: ResMut<TestReactRecorder>, mut events: ReactEventReader<TestEvent>|
+            {
+                recorder.0.push(events.next().unwrap().0);
+            }
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_react_res(In(new_val): In<usize>, mut rcommands: ReactCommands, mut react_res: ReactResMut<TestReactRes>)
+{
+    react_res.get_mut(&mut rcommands).0 = new_val;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn send_event(In(val): In<usize>, mut rcommands: ReactCommands)
+{
+    rcommands.send(TestEvent(val));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn receiving_app() -> App
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+
+    app
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn forward_react_events()
+{
+    // setup
+    let (sender, receiver) = new_channel::<TestEvent>();
+    let mut sim_app = App::new();
+    sim_app.add_plugins(ReactPlugin)
+        .add_react_event::<TestEvent>()
+        .forward_react_event(sender);
+    let mut ui_app = receiving_app();
+    ui_app.receive_react_events(receiver);
+    syscall(&mut ui_app.world, (), on_event);
+
+    // send events in the sim world
+    syscall(&mut sim_app.world, 1, send_event);
+    syscall(&mut sim_app.world, 2, send_event);
+    assert!(ui_app.world.resource::<TestReactRecorder>().0.is_empty());
+
+    // receive (reactions in order)
+    ui_app.update();
+    assert_eq!(ui_app.world.resource::<TestReactRecorder>().0, vec![1, 2]);
+
+    // update with nothing forwarded (no reactions)
+    ui_app.update();
+    assert_eq!(ui_app.world.resource::<TestReactRecorder>().0, vec![1, 2]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn forward_react_resource()
+{
+    // setup
+    let (sender, receiver) = new_channel::<TestReactRes>();
+    let mut sim_app = App::new();
+    sim_app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes(5))
+        .forward_react_resource(sender);
+    let mut ui_app = receiving_app();
+    ui_app.receive_react_resource(receiver);
+    syscall(&mut ui_app.world, (), on_resource_mutation);
+
+    // initial value is forwarded (resource inserted, reaction)
+    ui_app.update();
+    assert_eq!(ui_app.world.react_resource::<TestReactRes>().0, 5);
+    assert_eq!(ui_app.world.resource::<TestReactRecorder>().0, vec![5]);
+
+    // mutate twice in the sim world (latest value applied, one reaction)
+    syscall(&mut sim_app.world, 6, update_react_res);
+    syscall(&mut sim_app.world, 7, update_react_res);
+    ui_app.update();
+    assert_eq!(ui_app.world.react_resource::<TestReactRes>().0, 7);
+    assert_eq!(ui_app.world.resource::<TestReactRecorder>().0, vec![5, 7]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,393 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent, Clone)]
+struct TestComponent(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Default, Clone)]
+struct TestReactRes(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Default, Clone)]
+struct UntrackedReactRes(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct TestReactRecorder(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_resource_mutation(mut rcommands: ReactCommands)
+{
+    rcommands.on(resource_mutation::<TestReactRes>(),
+            |mut recorder: ResMut<TestReactRecorder>, resource: ReactRes<TestReactRes>|
+            {
+                recorder.0 = resource.0;
+            }
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_entity_mutation(In(entity): In<Entity>, mut rcommands: ReactCommands)
+{
+    rcommands.on(entity_mutation::<TestComponent>(entity),
+            move |mut recorder: ResMut<TestReactRecorder>, components: Query<&React<TestComponent>>|
+            {
+                recorder.0 = components.get(entity).unwrap().0;
+            }
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_react_res(In(new_val): In<usize>, mut rcommands: ReactCommands, mut react_res: ReactResMut<TestReactRes>)
+{
+    react_res.get_mut(&mut rcommands).0 = new_val;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_untracked_res(
+    In(new_val)   : In<usize>,
+    mut rcommands : ReactCommands,
+    mut react_res : ReactResMut<UntrackedReactRes>
+){
+    react_res.get_mut(&mut rcommands).0 = new_val;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_test_entity(
+    In((entity, new_val)) : In<(Entity, usize)>,
+    mut rcommands         : ReactCommands,
+    mut test_entities     : Query<&mut React<TestComponent>>,
+){
+    test_entities
+        .get_mut(entity)
+        .unwrap()
+        .get_mut(&mut rcommands)
+        .0 = new_val;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn insert_on_test_entity(In((entity, component)): In<(Entity, TestComponent)>, mut rcommands: ReactCommands)
+{
+    rcommands.insert(entity, component);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn checkpoint(mut rcommands: ReactCommands)
+{
+    rcommands.checkpoint();
+}
+
+fn undo(mut rcommands: ReactCommands)
+{
+    rcommands.undo();
+}
+
+fn redo(mut rcommands: ReactCommands)
+{
+    rcommands.redo();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn test_app() -> App
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .init_react_resource::<TestReactRes>()
+        .init_react_resource::<UntrackedReactRes>()
+        .track_resource_history::<TestReactRes>()
+        .track_component_history::<TestComponent>();
+
+    app
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn history_resource_undo_redo()
+{
+    // setup
+    let mut app = test_app();
+    let world = &mut app.world;
+    syscall(world, (), on_resource_mutation);
+
+    // mutate
+    syscall(world, 1, update_react_res);
+    syscall(world, (), checkpoint);
+    syscall(world, 2, update_react_res);
+    syscall(world, (), checkpoint);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 2);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+
+    // undo (reaction)
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 1);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // undo (reaction)
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 0);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // undo with empty history (no change)
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 0);
+
+    // redo (reaction)
+    syscall(world, (), redo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 1);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // redo (reaction)
+    syscall(world, (), redo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 2);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+
+    // redo with empty redo stack (no change)
+    syscall(world, (), redo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn history_component_undo_redo()
+{
+    // setup
+    let mut app = test_app();
+    let world = &mut app.world;
+    let test_entity = world.spawn_empty().id();
+    syscall(world, (test_entity, TestComponent(0)), insert_on_test_entity);
+    syscall(world, test_entity, on_entity_mutation);
+
+    // mutate
+    syscall(world, (test_entity, 5), update_test_entity);
+    syscall(world, (), checkpoint);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 5);
+
+    // undo (reaction)
+    syscall(world, (), undo);
+    assert_eq!(world.get::<React<TestComponent>>(test_entity).unwrap().0, 0);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // redo (reaction)
+    syscall(world, (), redo);
+    assert_eq!(world.get::<React<TestComponent>>(test_entity).unwrap().0, 5);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 5);
+
+    // undo after the component was removed (entry is discarded)
+    world.entity_mut(test_entity).remove::<React<TestComponent>>();
+    syscall(world, (), undo);
+    syscall(world, (test_entity, TestComponent(7)), insert_on_test_entity);
+    syscall(world, (), redo);
+    assert_eq!(world.get::<React<TestComponent>>(test_entity).unwrap().0, 7);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn history_checkpoint_groups_mutations()
+{
+    // setup
+    let mut app = test_app();
+    let world = &mut app.world;
+    let test_entity = world.spawn_empty().id();
+    syscall(world, (test_entity, TestComponent(0)), insert_on_test_entity);
+
+    // mutate several times in one group
+    syscall(world, 1, update_react_res);
+    syscall(world, 2, update_react_res);
+    syscall(world, (test_entity, 3), update_test_entity);
+    syscall(world, (), checkpoint);
+
+    // undo restores all values from before the group
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 0);
+    assert_eq!(world.get::<React<TestComponent>>(test_entity).unwrap().0, 0);
+
+    // redo restores all values from the end of the group
+    syscall(world, (), redo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 2);
+    assert_eq!(world.get::<React<TestComponent>>(test_entity).unwrap().0, 3);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn history_undo_closes_open_group()
+{
+    // setup
+    let mut app = test_app();
+    let world = &mut app.world;
+
+    // mutate without a checkpoint
+    syscall(world, 1, update_react_res);
+
+    // undo (the open group is undone)
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn history_new_mutation_clears_redo()
+{
+    // setup
+    let mut app = test_app();
+    let world = &mut app.world;
+
+    // mutate and undo
+    syscall(world, 1, update_react_res);
+    syscall(world, (), checkpoint);
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 0);
+
+    // new mutation
+    syscall(world, 10, update_react_res);
+    syscall(world, (), checkpoint);
+
+    // redo (nothing to redo)
+    syscall(world, (), redo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 10);
+
+    // undo (back to the value before the new mutation)
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn history_ignores_untracked()
+{
+    // setup
+    let mut app = test_app();
+    let world = &mut app.world;
+
+    // mutate untracked resource
+    syscall(world, 1, update_untracked_res);
+    syscall(world, (), checkpoint);
+
+    // undo (no change)
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<UntrackedReactRes>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn history_reactions_are_not_recorded()
+{
+    // setup
+    let mut app = test_app();
+    let world = &mut app.world;
+    let test_entity = world.spawn_empty().id();
+    syscall(world, (test_entity, TestComponent(0)), insert_on_test_entity);
+
+    // reactor that copies the resource into the component
+    syscall(world, (),
+            move |mut rcommands: ReactCommands|
+            {
+                rcommands.on(resource_mutation::<TestReactRes>(),
+                        move
+                        |
+                            mut rcommands : ReactCommands,
+                            resource      : ReactRes<TestReactRes>,
+                            mut q         : Query<&mut React<TestComponent>>
+                        |
+                        {
+                            q.get_mut(test_entity).unwrap().get_mut(&mut rcommands).0 = resource.0;
+                        }
+                    );
+            }
+        );
+
+    // mutate (the reaction's component mutation is recorded in the same group)
+    syscall(world, 1, update_react_res);
+    syscall(world, (), checkpoint);
+    assert_eq!(world.get::<React<TestComponent>>(test_entity).unwrap().0, 1);
+
+    // undo (the reaction runs but does not clear the redo stack)
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 0);
+    assert_eq!(world.get::<React<TestComponent>>(test_entity).unwrap().0, 0);
+
+    // redo
+    syscall(world, (), redo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 1);
+    assert_eq!(world.get::<React<TestComponent>>(test_entity).unwrap().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn history_max_depth_evicts_oldest()
+{
+    // setup
+    let mut app = test_app();
+    app.set_react_history_depth(Some(2));
+    let world = &mut app.world;
+
+    // mutate
+    for val in 1..=4
+    {
+        syscall(world, val, update_react_res);
+        syscall(world, (), checkpoint);
+    }
+
+    // only the two most recent groups can be undone
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 3);
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 2);
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 2);
+
+    // redo still works after eviction
+    syscall(world, (), redo);
+    syscall(world, (), redo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 4);
+
+    // shrinking the depth discards the oldest groups immediately
+    app.set_react_history_depth(Some(1));
+    let world = &mut app.world;
+    syscall(world, (), undo);
+    syscall(world, (), undo);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 3);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
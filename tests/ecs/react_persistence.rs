@@ -0,0 +1,203 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+//standard shortcuts
+use std::path::PathBuf;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Default, Serialize, Deserialize)]
+struct TestReactRes(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Default, Serialize, Deserialize)]
+struct TestReactResOther(String);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct TestReactRecorder(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_resource_mutation(mut rcommands: ReactCommands)
+{
+    rcommands.on(resource_mutation::<TestReactRes>(),
+            |mut recorder: ResMut<TestReactRecorder>, resource: ReactRes<TestReactRes>|
+            {
+                recorder.0 = resource.0;
+            }
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_react_res(In(new_val): In<usize>, mut rcommands: ReactCommands, mut react_res: ReactResMut<TestReactRes>)
+{
+    react_res.get_mut(&mut rcommands).0 = new_val;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn test_path(name: &str) -> PathBuf
+{
+    let path = std::env::temp_dir()
+        .join("bevy_kot_tests")
+        .join(format!("{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn test_app(path: PathBuf, format: PersistFormat) -> App
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .init_react_resource::<TestReactRes>()
+        .persist_react_resource::<TestReactRes>(path, format);
+
+    app
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn round_trip(path: PathBuf, format: PersistFormat)
+{
+    // setup
+    let mut app = test_app(path.clone(), format);
+    let world = &mut app.world;
+    syscall(world, (), on_resource_mutation);
+
+    // save
+    syscall(world, 5, update_react_res);
+    world.save_react_resource::<TestReactRes>().unwrap();
+    assert!(path.exists());
+
+    // change the value
+    syscall(world, 10, update_react_res);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 10);
+
+    // load (reaction)
+    world.load_react_resource::<TestReactRes>().unwrap();
+    assert_eq!(world.react_resource::<TestReactRes>().0, 5);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 5);
+
+    let _ = std::fs::remove_file(&path);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn persistence_json_round_trip()
+{
+    round_trip(test_path("round_trip.json"), PersistFormat::Json);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn persistence_ron_round_trip()
+{
+    round_trip(test_path("round_trip.ron"), PersistFormat::Ron);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn persistence_load_inserts_missing_resource()
+{
+    // save in one app
+    let path = test_path("insert.ron");
+    let mut app = test_app(path.clone(), PersistFormat::Ron);
+    syscall(&mut app.world, 3, update_react_res);
+    app.world.save_react_resource::<TestReactRes>().unwrap();
+
+    // load in an app without the resource
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .persist_react_resource::<TestReactRes>(path.clone(), PersistFormat::Ron);
+    let world = &mut app.world;
+    syscall(world, (), on_resource_mutation);
+
+    // load (resource inserted, reaction)
+    world.load_react_resource::<TestReactRes>().unwrap();
+    assert_eq!(world.react_resource::<TestReactRes>().0, 3);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 3);
+
+    let _ = std::fs::remove_file(&path);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn persistence_bulk_save_load()
+{
+    // setup
+    let path = test_path("bulk.json");
+    let other_path = test_path("bulk_other.json");
+    let mut app = test_app(path.clone(), PersistFormat::Json);
+    app.init_react_resource::<TestReactResOther>()
+        .persist_react_resource::<TestReactResOther>(other_path.clone(), PersistFormat::Json);
+    let world = &mut app.world;
+
+    // load with no files (missing files are skipped)
+    world.load_react_resources().unwrap();
+
+    // save all
+    syscall(world, 7, update_react_res);
+    world.get_react_resource_mut_noreact::<TestReactResOther>().unwrap().0 = String::from("hello");
+    world.save_react_resources().unwrap();
+
+    // load all
+    syscall(world, 0, update_react_res);
+    world.get_react_resource_mut_noreact::<TestReactResOther>().unwrap().0 = String::new();
+    world.load_react_resources().unwrap();
+    assert_eq!(world.react_resource::<TestReactRes>().0, 7);
+    assert_eq!(world.react_resource::<TestReactResOther>().0, "hello");
+
+    let _ = std::fs::remove_file(&path);
+    let _ = std::fs::remove_file(&other_path);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn persistence_errors()
+{
+    // setup
+    let path = test_path("errors.json");
+    let mut app = test_app(path.clone(), PersistFormat::Json);
+    let world = &mut app.world;
+
+    // unregistered resource
+    assert!(matches!(world.save_react_resource::<TestReactResOther>(), Err(PersistError::Unregistered(_))));
+
+    // missing file
+    assert!(world.load_react_resource::<TestReactRes>().unwrap_err().is_not_found());
+
+    // malformed file
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "not json").unwrap();
+    assert!(matches!(world.load_react_resource::<TestReactRes>(), Err(PersistError::Deserialize(_))));
+
+    let _ = std::fs::remove_file(&path);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
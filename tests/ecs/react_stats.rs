@@ -0,0 +1,212 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::sync::{Arc, Mutex};
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Default)]
+struct TestReactRes;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent)]
+struct TestComponent;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+struct TestEvent;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Collects formatted log output.
+#[derive(Clone, Default)]
+struct LogCapture(Arc<Mutex<Vec<u8>>>);
+
+impl LogCapture
+{
+    fn count(&self, pattern: &str) -> usize
+    {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).matches(pattern).count()
+    }
+}
+
+impl std::io::Write for LogCapture
+{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize>
+    {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn test_app() -> App
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_react_resource::<TestReactRes>()
+        .set_reactor_warning_threshold(2);
+
+    app
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn stats_count_reactors()
+{
+    // setup
+    let mut app = test_app();
+    let world = &mut app.world;
+    let entity = world.spawn_empty().id();
+    assert_eq!(world.react_stats().total, 0);
+
+    // register reactors
+    syscall(world, (),
+            move |mut rcommands: ReactCommands|
+            {
+                rcommands.on(resource_mutation::<TestReactRes>(), || ());
+                rcommands.on(resource_mutation::<TestReactRes>(), || ());
+                rcommands.on((insertion::<TestComponent>(), mutation::<TestComponent>()), |_: In<Entity>| ());
+                rcommands.on(entity_mutation::<TestComponent>(entity), || ());
+                rcommands.on(event::<TestEvent>(), || ());
+                rcommands.on_despawn(entity, || ()).unwrap();
+            }
+        );
+
+    // check stats
+    let stats = world.react_stats();
+    assert_eq!(stats.total, 7);
+    assert_eq!(stats.trigger_count(ReactTriggerKind::ResourceMutation), 2);
+    assert_eq!(stats.trigger_count(ReactTriggerKind::ComponentInsertion), 1);
+    assert_eq!(stats.trigger_count(ReactTriggerKind::ComponentMutation), 1);
+    assert_eq!(stats.trigger_count(ReactTriggerKind::EntityMutation), 1);
+    assert_eq!(stats.trigger_count(ReactTriggerKind::Event), 1);
+    assert_eq!(stats.trigger_count(ReactTriggerKind::Despawn), 1);
+    assert_eq!(stats.type_count::<TestReactRes>(), 2);
+    assert_eq!(stats.type_count::<TestComponent>(), 3);
+    assert_eq!(stats.type_count::<TestEvent>(), 1);
+    assert_eq!(stats.entity_count(entity), 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn stats_track_revoked_reactors()
+{
+    // setup
+    let mut app = test_app();
+    let world = &mut app.world;
+    let entity = world.spawn_empty().id();
+
+    // register reactors
+    let tokens = syscall(world, (),
+            move |mut rcommands: ReactCommands| -> Vec<RevokeToken>
+            {
+                vec![
+                    rcommands.on(resource_mutation::<TestReactRes>(), || ()),
+                    rcommands.on(entity_insertion::<TestComponent>(entity), || ()),
+                    rcommands.on_despawn(entity, || ()).unwrap(),
+                ]
+            }
+        );
+    assert_eq!(world.react_stats().total, 3);
+
+    // revoke reactors
+    syscall(world, tokens,
+            |In(tokens): In<Vec<RevokeToken>>, mut rcommands: ReactCommands|
+            {
+                for token in tokens { rcommands.revoke(token); }
+            }
+        );
+
+    let stats = world.react_stats();
+    assert_eq!(stats.total, 0);
+    assert_eq!(stats.entity_count(entity), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn stats_warn_once_per_leaking_target()
+{
+    // setup
+    let mut app = test_app();
+    let world = &mut app.world;
+    let capture = LogCapture::default();
+    let writer = capture.clone();
+    let subscriber = tracing_subscriber::FmtSubscriber::builder()
+        .with_max_level(tracing::Level::WARN)
+        .with_writer(move || writer.clone())
+        .finish();
+    let warning = "reactor count exceeded threshold";
+
+    tracing::subscriber::with_default(subscriber, ||
+        {
+            // register reactors up to the threshold (no warning)
+            let tokens = syscall(world, (),
+                    |mut rcommands: ReactCommands| -> Vec<RevokeToken>
+                    {
+                        vec![
+                            rcommands.on(resource_mutation::<TestReactRes>(), || ()),
+                            rcommands.on(resource_mutation::<TestReactRes>(), || ()),
+                        ]
+                    }
+                );
+            assert_eq!(capture.count(warning), 0);
+
+            // exceed the threshold (warning)
+            syscall(world, (),
+                    |mut rcommands: ReactCommands|
+                    {
+                        rcommands.on(resource_mutation::<TestReactRes>(), || ());
+                        rcommands.on(resource_mutation::<TestReactRes>(), || ());
+                    }
+                );
+            assert_eq!(capture.count(warning), 1);
+
+            // drop below the threshold and exceed it again (no repeat warning)
+            syscall(world, tokens,
+                    |In(tokens): In<Vec<RevokeToken>>, mut rcommands: ReactCommands|
+                    {
+                        for token in tokens { rcommands.revoke(token); }
+                    }
+                );
+            syscall(world, (),
+                    |mut rcommands: ReactCommands|
+                    {
+                        rcommands.on(resource_mutation::<TestReactRes>(), || ());
+                        rcommands.on(resource_mutation::<TestReactRes>(), || ());
+                    }
+                );
+            assert_eq!(world.react_stats().type_count::<TestReactRes>(), 4);
+            assert_eq!(capture.count(warning), 1);
+
+            // exceed the threshold on a different target (warning)
+            syscall(world, (),
+                    |mut rcommands: ReactCommands|
+                    {
+                        for _ in 0..3 { rcommands.on(event::<TestEvent>(), || ()); }
+                    }
+                );
+            assert_eq!(capture.count(warning), 2);
+        }
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
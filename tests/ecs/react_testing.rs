@@ -0,0 +1,139 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Default)]
+struct TestReactRes(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent)]
+struct TestComponent;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn resource_reactor() {}
+
+fn other_resource_reactor() {}
+
+fn component_reactor() {}
+
+fn despawn_reactor() {}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_react_res(In(new_val): In<usize>, mut rcommands: ReactCommands, mut react_res: ReactResMut<TestReactRes>)
+{
+    react_res.get_mut(&mut rcommands).0 = new_val;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_app_records_reactions()
+{
+    // setup
+    let mut app = ReactTestApp::new();
+    app.init_react_resource::<TestReactRes>();
+    app.syscall((),
+            |mut rcommands: ReactCommands|
+            {
+                rcommands.on(resource_mutation::<TestReactRes>(), resource_reactor);
+            }
+        );
+    app.assert_reacted_by(&resource_reactor, 0);
+
+    // mutate twice
+    app.syscall(1, update_react_res);
+    app.syscall(2, update_react_res);
+    app.assert_reacted_by(&resource_reactor, 2);
+    app.assert_reacted_by(&other_resource_reactor, 0);
+
+    // clear
+    app.clear_reactions();
+    assert!(app.reactions().is_empty());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_app_records_named_reactor_types()
+{
+    // setup
+    let mut app = ReactTestApp::new();
+    app.init_react_resource::<TestReactRes>();
+    let reactor = |mut count: Local<usize>| { *count += 1; };
+    app.syscall((), move |mut rcommands: ReactCommands| { rcommands.on(resource_mutation::<TestReactRes>(), reactor); });
+
+    // mutate
+    app.syscall(1, update_react_res);
+
+    // the reactor type can be named via generics
+    fn count_of<R: 'static>(app: &ReactTestApp, _: &R) -> usize { app.reaction_count::<R>() }
+    assert_eq!(count_of(&app, &reactor), 1);
+    assert_eq!(app.reactions()[0], ReactorId::of_val(&reactor));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_app_reaction_order()
+{
+    // setup
+    let mut app = ReactTestApp::new();
+    app.init_react_resource::<TestReactRes>();
+    let entity = app.world.spawn_empty().id();
+    app.syscall((),
+            move |mut rcommands: ReactCommands|
+            {
+                rcommands.on(resource_mutation::<TestReactRes>(), resource_reactor);
+                rcommands.once(resource_mutation::<TestReactRes>(), other_resource_reactor);
+                rcommands.on(entity_insertion::<TestComponent>(entity), component_reactor);
+                rcommands.on_despawn(entity, despawn_reactor).unwrap();
+            }
+        );
+
+    // mutate resource (once reactor runs once)
+    app.syscall(1, update_react_res);
+    app.syscall(2, update_react_res);
+
+    // insert component then despawn
+    app.syscall((), move |mut rcommands: ReactCommands| rcommands.insert(entity, TestComponent));
+    app.world.despawn(entity);
+    react_to_all_removals_and_despawns(&mut app.world);
+
+    app.assert_reaction_order([
+            ReactorId::of_val(&resource_reactor),
+            ReactorId::of_val(&other_resource_reactor),
+            ReactorId::of_val(&resource_reactor),
+            ReactorId::of_val(&component_reactor),
+            ReactorId::of_val(&despawn_reactor),
+        ]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+#[should_panic]
+fn test_app_assert_reacted_fails()
+{
+    let mut app = ReactTestApp::new();
+    app.init_react_resource::<TestReactRes>();
+    app.syscall((), |mut rcommands: ReactCommands| { rcommands.on(resource_mutation::<TestReactRes>(), resource_reactor); });
+    app.syscall(1, update_react_res);
+    app.assert_reacted_by(&resource_reactor, 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
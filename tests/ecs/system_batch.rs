@@ -0,0 +1,106 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct Value(u32);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct Log(Vec<u32>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn read_and_log(In(input): In<u32>, values: Query<&Value>, mut commands: Commands, mut calls: Local<u32>) -> u32
+{
+    *calls += 1;
+    let sum: u32 = values.iter().map(|value| value.0).sum();
+    commands.add(move |world: &mut World| world.resource_mut::<Log>().0.push(input));
+    sum + input + *calls * 100
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn batch_runs_all_systems()
+{
+    let mut world = World::new();
+    world.init_resource::<Log>();
+    world.spawn(Value(1));
+    world.spawn(Value(2));
+
+    let sys_ids: Vec<SysId<u32, u32>> = (0..20).map(|_| spawn_system(&mut world, read_and_log)).collect();
+
+    // outputs and deferred commands are in batch order
+    let results = spawned_syscall_batch(&mut world, sys_ids.iter().rev().copied().zip(0..20));
+    assert_eq!(results, (0..20).map(|i| Ok(i + 3 + 100)).collect::<Vec<_>>());
+    assert_eq!(world.resource::<Log>().0, (0..20).collect::<Vec<_>>());
+
+    // systems are returned with their state preserved
+    assert_eq!(spawned_syscall(&mut world, sys_ids[0], 0), Ok(203));
+    let results = spawned_syscall_batch(&mut world, sys_ids.iter().copied().zip(0..20));
+    assert_eq!(results[0], Ok(303));
+    assert_eq!(results[1], Ok(204));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn batch_errors()
+{
+    let mut world = World::new();
+    world.init_resource::<Log>();
+    let sys_id = spawn_system(&mut world, read_and_log);
+    let despawned = spawn_system(&mut world, read_and_log);
+    world.despawn(despawned.entity());
+
+    let results = spawned_syscall_batch(&mut world, [(sys_id, 1), (despawned, 2), (sys_id, 3)]);
+    assert_eq!(
+            results,
+            vec![Ok(101), Err(SysCallError::MissingEntity(despawned.entity())), Err(SysCallError::Recursive)]
+        );
+    assert_eq!(world.resource::<Log>().0, vec![1]);
+
+    // the system is still usable after the batch
+    assert_eq!(spawned_syscall(&mut world, sys_id, 1), Ok(201));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn batch_conflicting_and_exclusive_systems()
+{
+    let mut world = World::new();
+    world.init_resource::<Log>();
+    world.spawn(Value(0));
+
+    // conflicting systems run one at a time
+    let increment = |In(amount): In<u32>, mut values: Query<&mut Value>|
+    {
+        for mut value in values.iter_mut() { value.0 += amount; }
+    };
+    let mut batch: Vec<(SysId<u32, ()>, u32)> = (1..=10).map(|i| (spawn_system(&mut world, increment), i)).collect();
+
+    // exclusive systems can be batched
+    let log = |In(amount): In<u32>, world: &mut World| world.resource_mut::<Log>().0.push(amount);
+    batch.push((spawn_system(&mut world, log), 100));
+
+    let results = spawned_syscall_batch(&mut world, batch);
+    assert!(results.iter().all(|result| result.is_ok()));
+
+    let values: Vec<u32> = world.query::<&Value>().iter(&world).map(|value| value.0).collect();
+    assert_eq!(values, vec![55]);
+    assert_eq!(world.resource::<Log>().0, vec![100]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
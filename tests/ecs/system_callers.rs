@@ -0,0 +1,331 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::{SystemParam, SystemState};
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::marker::PhantomData;
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn accumulate(In(input): In<u16>, mut local: Local<u16>) -> u16
+{
+    *local += input;
+    *local
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct TestRecorder(u16);
+
+fn record(In(input): In<u16>, mut recorder: ResMut<TestRecorder>)
+{
+    recorder.0 = input;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn typed_spawned_syscall()
+{
+    let mut world = World::new();
+    let sys_id1: SysId<u16, u16> = spawn_system(&mut world, accumulate);
+    let sys_id2 = spawn_system(&mut world, accumulate);
+
+    assert_eq!(spawned_syscall(&mut world, sys_id1, 1), Ok(1));
+    assert_eq!(spawned_syscall(&mut world, sys_id1, 1), Ok(2));
+    assert_eq!(spawned_syscall(&mut world, sys_id2, 10), Ok(10));
+    assert_eq!(spawned_syscall(&mut world, sys_id2, 10), Ok(20));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn erased_sys_id_round_trip()
+{
+    let mut world = World::new();
+    let sys_id = spawn_system(&mut world, accumulate);
+
+    // store erased ids together
+    let erased: Vec<AnySysId> = vec![sys_id.erase(), AnySysId::from(sys_id)];
+    assert_eq!(erased[0], erased[1]);
+    assert_eq!(erased[0].entity(), sys_id.entity());
+
+    // recover the typed id
+    let typed = erased[0].typed::<u16, u16>();
+    assert_eq!(typed, sys_id);
+    assert_eq!(spawned_syscall(&mut world, typed, 3), Ok(3));
+
+    // recovering with the wrong types fails at runtime
+    assert!(spawned_syscall(&mut world, erased[0].typed::<u32, u16>(), 3).is_err());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn typed_spawned_syscall_commands()
+{
+    let mut world = World::new();
+    world.init_resource::<TestRecorder>();
+    let sys_id: SysId<u16> = spawn_system(&mut world, record);
+
+    syscall(&mut world, sys_id, |In(sys_id): In<SysId<u16>>, mut commands: Commands| commands.spawned_syscall(sys_id, 7));
+    assert_eq!(world.resource::<TestRecorder>().0, 7);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn spawned_syscall_errors()
+{
+    let mut world = World::new();
+
+    // missing entity
+    let sys_id = spawn_system(&mut world, accumulate);
+    world.despawn(sys_id.entity());
+    assert_eq!(spawned_syscall(&mut world, sys_id, 1), Err(SysCallError::MissingEntity(sys_id.entity())));
+
+    // wrong system type
+    let sys_id = spawn_system(&mut world, accumulate);
+    let wrong_id = sys_id.erase().typed::<u16, ()>();
+    assert_eq!(spawned_syscall(&mut world, wrong_id, 1), Err(SysCallError::MissingSystem(sys_id.entity())));
+
+    // recursive call
+    let sys_id: SysId<AnySysId, Result<(), SysCallError>> = spawn_system(&mut world,
+            |In(sys_id): In<AnySysId>, world: &mut World| -> Result<(), SysCallError>
+            {
+                spawned_syscall(world, sys_id.typed::<AnySysId, Result<(), SysCallError>>(), sys_id)?
+            }
+        );
+    assert_eq!(spawned_syscall(&mut world, sys_id, sys_id.erase()), Ok(Err(SysCallError::Recursive)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn named_syscall_direct_errors()
+{
+    let mut world = World::new();
+
+    // missing named system
+    let sys_name = SysName::new::<u16>("a");
+    assert_eq!(named_syscall_direct::<u16, u16>(&mut world, sys_name, 1), Err(SysCallError::MissingNamedSystem(sys_name)));
+
+    // registered named system
+    register_named_system(&mut world, sys_name, accumulate);
+    assert_eq!(named_syscall_direct::<u16, u16>(&mut world, sys_name, 1), Ok(1));
+
+    // recursive call
+    let sys_name = SysName::new::<u32>("b");
+    register_named_system(&mut world, sys_name,
+            move |In(()): In<()>, world: &mut World| -> Result<(), SysCallError>
+            {
+                named_syscall_direct::<(), Result<(), SysCallError>>(world, sys_name, ())?
+            }
+        );
+    assert_eq!(named_syscall_direct::<(), Result<(), SysCallError>>(&mut world, sys_name, ()), Ok(Err(SysCallError::Recursive)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Recurses `depth` times, returning the value of each level's `Local` counter (outer-most first).
+fn recurse(In(depth): In<u16>, world: &mut World, local: &mut SystemState<Local<u16>>) -> Vec<u16>
+{
+    let mut counter = local.get_mut(world);
+    *counter += 1;
+    let count = *counter;
+
+    let mut counts = vec![count];
+    if depth > 0 { counts.extend(named_syscall(world, "recurse", depth - 1, recurse)); }
+    counts
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn named_syscall_recursion()
+{
+    let mut world = World::new();
+
+    // each recursion depth has its own instance
+    assert_eq!(named_syscall(&mut world, "recurse", 2, recurse), vec![1, 1, 1]);
+    assert_eq!(named_syscall(&mut world, "recurse", 2, recurse), vec![2, 2, 2]);
+
+    // shallower calls reuse instances (the most recently returned instance is used first)
+    assert_eq!(named_syscall(&mut world, "recurse", 0, recurse), vec![3]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn syscall_recursion()
+{
+    fn recurse_syscall(In(depth): In<u16>, world: &mut World, local: &mut SystemState<Local<u16>>) -> Vec<u16>
+    {
+        let mut counter = local.get_mut(world);
+        *counter += 1;
+        let count = *counter;
+
+        let mut counts = vec![count];
+        if depth > 0 { counts.extend(syscall(world, depth - 1, recurse_syscall)); }
+        counts
+    }
+
+    let mut world = World::new();
+    assert_eq!(syscall(&mut world, 1, recurse_syscall), vec![1, 1]);
+    assert_eq!(syscall(&mut world, 1, recurse_syscall), vec![2, 2]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn named_system_replaced_while_running()
+{
+    let mut world = World::new();
+    let sys_name = SysName::new::<u64>("replace");
+
+    // the running instance replaces itself
+    register_named_system(&mut world, sys_name,
+            move |In(()): In<()>, world: &mut World| -> u16
+            {
+                register_named_system(world, sys_name, |In(()): In<()>| -> u16 { 2 });
+                1
+            }
+        );
+    assert_eq!(named_syscall_direct::<(), u16>(&mut world, sys_name, ()), Ok(1));
+
+    // the old instance was discarded
+    assert_eq!(named_syscall_direct::<(), u16>(&mut world, sys_name, ()), Ok(2));
+    assert_eq!(named_syscall_direct::<(), u16>(&mut world, sys_name, ()), Ok(2));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn named_system_registry()
+{
+    let mut world = World::new();
+    let a = SysName::new::<u16>("a");
+    let b = SysName::new::<u16>("b");
+    assert!(named_systems(&world).is_empty());
+    assert!(!is_named_system_registered(&world, a));
+
+    // registered systems are listed across input/output types
+    register_named_system(&mut world, a, accumulate);
+    register_named_system(&mut world, b, |In(()): In<()>| {});
+
+    let mut infos = named_systems(&world);
+    infos.sort_by_key(|info| info.input_type);
+    assert_eq!(infos.len(), 2);
+    assert_eq!(infos[0].name, b);
+    assert_eq!(infos[0].input_type, "()");
+    assert_eq!(infos[1].name, a);
+    assert_eq!(infos[1].input_type, "u16");
+    assert_eq!(infos[1].output_type, "u16");
+    assert!(infos[1].system_type.contains("accumulate"));
+    assert!(is_named_system_registered(&world, a));
+    assert!(is_named_system_registered(&world, b));
+
+    // replace the implementation in place
+    assert_eq!(named_syscall_direct::<u16, u16>(&mut world, a, 5), Ok(5));
+    assert_eq!(replace_named_system(&mut world, a, |In(input): In<u16>| input * 10), Ok(()));
+    assert_eq!(named_syscall_direct::<u16, u16>(&mut world, a, 5), Ok(50));
+    assert!(!named_systems(&world).iter().any(|info| info.system_type.contains("accumulate")));
+
+    // only registered systems can be replaced
+    let c = SysName::new::<u16>("c");
+    assert_eq!(replace_named_system(&mut world, c, accumulate), Err(SysCallError::MissingNamedSystem(c)));
+    assert!(!is_named_system_registered(&world, c));
+
+    // revoked systems are unregistered
+    world.resource_mut::<IdMappedSystems<(), ()>>().revoke_sysname(b);
+    assert!(!is_named_system_registered(&world, b));
+    assert_eq!(named_systems(&world).len(), 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(SystemParam, CallableSystem)]
+#[callable(arg = u32, out = u32, run = add_to_counter)]
+struct CallableCounter<'w, 's>
+{
+    counter: Local<'s, u32>,
+    #[system_param(ignore)]
+    _phantom: PhantomData<&'w ()>,
+}
+
+fn add_to_counter(context: &mut CallableCounter, _id: CallId, amount: u32) -> u32
+{
+    *context.counter += amount;
+    *context.counter
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn derived_callable_system()
+{
+    let mut world = World::new();
+
+    assert_eq!(call::<CallableCounter>(&mut world, CallId::new("a"), 1), 1);
+    assert_eq!(call::<CallableCounter>(&mut world, CallId::new("a"), 2), 3);
+    assert_eq!(call::<CallableCounter>(&mut world, CallId::new("b"), 10), 10);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(SystemParam)]
+struct Countdown<'w, 's>
+{
+    calls: Local<'s, u32>,
+    #[system_param(ignore)]
+    _phantom: PhantomData<&'w ()>,
+}
+
+impl<'w, 's> BasicCallableSystem for Countdown<'w, 's>
+{
+    type OutT = Vec<u32>;
+
+    fn system(world: &mut World, state: &mut SystemState<Self>, id: CallId) -> Vec<u32>
+    {
+        let calls = {
+            let mut context = state.get_mut(world);
+            *context.calls += 1;
+            *context.calls
+        };
+
+        // the world is free while the system runs, so recursive calls are allowed
+        let remaining = world.resource::<CountdownRemaining>().0;
+        let mut calls = vec![calls];
+        if remaining == 0 { return calls; }
+        world.resource_mut::<CountdownRemaining>().0 -= 1;
+        calls.extend(call_basic::<Countdown>(world, id));
+        calls
+    }
+}
+
+#[derive(Resource)]
+struct CountdownRemaining(u32);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn recursive_basic_callable_system()
+{
+    let mut world = World::new();
+
+    // each recursion depth has its own state
+    world.insert_resource(CountdownRemaining(2));
+    assert_eq!(call_basic::<Countdown>(&mut world, CallId::new("a")), vec![1, 1, 1]);
+    world.insert_resource(CountdownRemaining(1));
+    assert_eq!(call_basic::<Countdown>(&mut world, CallId::new("a")), vec![2, 2]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
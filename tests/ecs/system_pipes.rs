@@ -0,0 +1,154 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct Stages(Vec<&'static str>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn spawn_marker(In(input): In<u16>, mut commands: Commands) -> u16
+{
+    commands.spawn(Marker);
+    input + 1
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn count_markers(In(input): In<u16>, markers: Query<(), With<Marker>>) -> (u16, usize)
+{
+    (input, markers.iter().count())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn check_positive(In(input): In<i32>, mut stages: ResMut<Stages>) -> Result<i32, String>
+{
+    stages.0.push("check");
+    if input <= 0 { return Err(format!("{input} is not positive")); }
+    Ok(input)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn halve(In(input): In<i32>, mut stages: ResMut<Stages>) -> Result<i32, String>
+{
+    stages.0.push("halve");
+    Ok(input / 2)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct Marker;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn syscall_pipe_applies_deferred_between_stages()
+{
+    let mut world = World::new();
+
+    // the second stage sees the entity spawned by the first stage
+    assert_eq!(syscall_pipe(&mut world, 1u16, (spawn_marker, count_markers)), (2, 1));
+    assert_eq!(syscall_pipe(&mut world, 5u16, (spawn_marker, spawn_marker, count_markers)), (7, 3));
+    assert_eq!(syscall_pipe(&mut world, 5u16, (spawn_marker,)), 6);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn syscall_try_pipe_short_circuits()
+{
+    let mut world = World::new();
+    world.init_resource::<Stages>();
+
+    assert_eq!(syscall_try_pipe(&mut world, 8, (check_positive, halve, halve)), Ok(2));
+    assert_eq!(world.resource::<Stages>().0, vec!["check", "halve", "halve"]);
+
+    world.resource_mut::<Stages>().0.clear();
+    assert_eq!(syscall_try_pipe(&mut world, -1, (check_positive, halve, halve)), Err(String::from("-1 is not positive")));
+    assert_eq!(world.resource::<Stages>().0, vec!["check"]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn spawned_syscall_pipe_mixed_stages()
+{
+    let mut world = World::new();
+    let sys_id = spawn_system(&mut world, spawn_marker);
+    let sys_name = SysName::new::<Marker>("double");
+    register_named_system(&mut world, sys_name, |In(input): In<u16>| input * 2);
+
+    // spawned, named, and plain stages can be mixed
+    assert_eq!(spawned_syscall_pipe(&mut world, 1u16, (sys_id, sys_name, count_markers)), Ok((4, 1)));
+
+    // missing stages short-circuit
+    let missing = SysName::new::<Marker>("missing");
+    assert_eq!(
+            spawned_syscall_pipe::<u16, (u16, usize), _, _>(&mut world, 1u16, (sys_id, missing, count_markers)),
+            Err(SysCallError::MissingNamedSystem(missing))
+        );
+    assert_eq!(syscall(&mut world, 0u16, count_markers), (0, 2));
+
+    world.despawn(sys_id.entity());
+    assert_eq!(
+            spawned_syscall_pipe(&mut world, 1u16, (sys_id, count_markers)),
+            Err(SysCallError::MissingEntity(sys_id.entity()))
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+enum PipeError
+{
+    SysCall(SysCallError),
+    Check(String),
+}
+
+impl From<SysCallError> for PipeError
+{
+    fn from(err: SysCallError) -> Self { PipeError::SysCall(err) }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn spawned_syscall_try_pipe_short_circuits()
+{
+    let mut world = World::new();
+    world.init_resource::<Stages>();
+    let check = spawn_system(&mut world,
+            |In(input): In<i32>, stages: ResMut<Stages>| check_positive(In(input), stages).map_err(PipeError::Check)
+        );
+    let halve = spawn_system(&mut world,
+            |In(input): In<i32>, stages: ResMut<Stages>| halve(In(input), stages).map_err(PipeError::Check)
+        );
+
+    assert_eq!(spawned_syscall_try_pipe(&mut world, 8, (check, halve)), Ok(4));
+    assert_eq!(
+            spawned_syscall_try_pipe(&mut world, 0, (check, halve)),
+            Err(PipeError::Check(String::from("0 is not positive")))
+        );
+    assert_eq!(world.resource::<Stages>().0, vec!["check", "halve", "check"]);
+
+    // invocation failures are converted to the pipe's error type
+    world.despawn(halve.entity());
+    assert_eq!(
+            spawned_syscall_try_pipe(&mut world, 8, (check, halve)),
+            Err(PipeError::SysCall(SysCallError::MissingEntity(halve.entity())))
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
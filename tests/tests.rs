@@ -1,3 +1,4 @@
 //test modules
 mod ecs;
 mod misc;
+mod ui;
@@ -0,0 +1,166 @@
+//local shortcuts
+use super::utils::*;
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParamItem;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Left mouse button source that never cleans up element states.
+#[derive(Resource, Default)]
+struct NoCleanupMouse;
+
+impl InteractionSource for NoCleanupMouse
+{
+    type SourceParam = SRes<Input<MouseButton>>;
+    type LunexUi     = MainUi;
+    type LunexCursor = MainMouseCursor;
+
+    fn just_clicked(&self, source: &SystemParamItem<Self::SourceParam>) -> bool
+    {
+        source.just_pressed(MouseButton::Left)
+    }
+    fn is_clicked(&self, source: &SystemParamItem<Self::SourceParam>) -> bool
+    {
+        source.pressed(MouseButton::Left)
+    }
+    fn just_unclicked(&self, source: &SystemParamItem<Self::SourceParam>) -> bool
+    {
+        source.just_released(MouseButton::Left)
+    }
+    fn cleanup_policy(&self) -> InteractionCleanupPolicy
+    {
+        InteractionCleanupPolicy::none()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct CleanupScene
+{
+    window : Entity,
+    cursor : Entity,
+    button : Entity,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A focused window, and a button at `(0, 0)..(50, 50)` that is hovered and pressed by the cursor.
+fn cleanup_scene<S: InteractionSource>(app: &mut App) -> CleanupScene
+{
+    let window = app.world.spawn(Window::default()).id();
+
+    let (mut ui, root) = new_ui_tree();
+    let button_widget = relative_widget(&mut ui, root.end("button"), (0., 50.), (0., 50.));
+    let _ = spawn_ui_tree(&mut app.world, ui, ());
+
+    let button = build_element::<S>(&mut app.world,
+            InteractiveElementBuilder::new()
+                .press_on_click()
+                .unpress_on_unclick_home_or_away()
+                .on_hover_start(log_call("hover"))
+                .on_unhover(log_call("unhover"))
+                .on_abortpress(log_call("abort")),
+            button_widget,
+        );
+
+    let cursor = spawn_cursor(&mut app.world);
+    place_cursor(&mut app.world, cursor, Some(window), Vec2::new(25., 25.));
+    press_mouse(&mut app.world);
+    app.update();
+    assert!(app.world.get::<Pressed>(button).is_some());
+    assert!(app.world.get::<Hovered>(button).is_some());
+    assert_eq!(take_calls(&mut app.world), vec!["hover"]);
+
+    CleanupScene{ window, cursor, button }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn cleanup_on_cursor_disabled()
+{
+    let mut app = ui_test_app();
+    let scene = cleanup_scene::<MouseLButtonMain>(&mut app);
+
+    app.world.entity_mut(scene.cursor).insert(Disabled);
+    app.update();
+    assert!(app.world.get::<Pressed>(scene.button).is_none());
+    assert!(app.world.get::<Hovered>(scene.button).is_none());
+    assert_eq!(take_calls(&mut app.world), vec!["abort", "unhover"]);
+
+    // nothing more happens while the cursor stays disabled
+    app.update();
+    assert_eq!(take_calls(&mut app.world), Vec::<&'static str>::new());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn cleanup_on_cursor_despawned()
+{
+    let mut app = ui_test_app();
+    let scene = cleanup_scene::<MouseLButtonMain>(&mut app);
+
+    app.world.despawn(scene.cursor);
+    app.update();
+    assert!(app.world.get::<Pressed>(scene.button).is_none());
+    assert!(app.world.get::<Hovered>(scene.button).is_none());
+    assert_eq!(take_calls(&mut app.world), vec!["abort", "unhover"]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn cleanup_on_focus_change()
+{
+    let mut app = ui_test_app();
+    let scene = cleanup_scene::<MouseLButtonMain>(&mut app);
+
+    // the press is aborted, and the button is re-hovered since the cursor is still over it
+    app.world.get_mut::<Window>(scene.window).unwrap().focused = false;
+    app.update();
+    assert!(app.world.get::<Pressed>(scene.button).is_none());
+    assert!(app.world.get::<Hovered>(scene.button).is_some());
+    assert_eq!(take_calls(&mut app.world), vec!["abort", "unhover", "hover"]);
+
+    // regaining focus also cleans up
+    app.world.get_mut::<Window>(scene.window).unwrap().focused = true;
+    app.update();
+    assert_eq!(take_calls(&mut app.world), vec!["unhover", "hover"]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn no_cleanup_policy()
+{
+    let mut app = ui_test_app();
+    app.register_interaction_source(NoCleanupMouse);
+    let scene = cleanup_scene::<NoCleanupMouse>(&mut app);
+
+    // focus change
+    app.world.get_mut::<Window>(scene.window).unwrap().focused = false;
+    app.update();
+    assert!(app.world.get::<Pressed>(scene.button).is_some());
+    assert!(app.world.get::<Hovered>(scene.button).is_some());
+    assert_eq!(take_calls(&mut app.world), Vec::<&'static str>::new());
+
+    // cursor lost
+    app.world.entity_mut(scene.cursor).insert(Disabled);
+    app.update();
+    assert!(app.world.get::<Pressed>(scene.button).is_some());
+    assert!(app.world.get::<Hovered>(scene.button).is_some());
+    assert_eq!(take_calls(&mut app.world), Vec::<&'static str>::new());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
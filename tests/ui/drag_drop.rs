@@ -0,0 +1,293 @@
+//local shortcuts
+use super::utils::*;
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy_lunex::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Eq, PartialEq)]
+struct InventoryItem(u32);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records drag callbacks and the drop target they saw.
+#[derive(Resource, Default)]
+struct DragLog(Vec<(&'static str, Option<Entity>)>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct DragScene
+{
+    cursor : Entity,
+    item   : Entity,
+    slot   : Entity,
+    lid    : Entity,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn log_drag(name: &'static str) -> impl FnMut(In<DragInfo>, ResMut<DragLog>) + Send + Sync + 'static
+{
+    move |In(info): In<DragInfo>, mut log: ResMut<DragLog>| log.0.push((name, info.drop_target()))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A draggable item at `(10, 10)..(30, 30)`, and a slot that accepts items at `(60, 60)..(90, 90)`.
+/// - The slot is covered by a lid that is a drop target for other payloads.
+fn drag_scene(app: &mut App) -> DragScene
+{
+    app.init_resource::<DragLog>();
+
+    let (mut ui, root) = new_ui_tree();
+    let item_widget = relative_widget(&mut ui, root.end("item"), (10., 30.), (10., 30.));
+    let slot_widget = relative_widget(&mut ui, root.end("slot"), (60., 90.), (60., 90.));
+    let lid_widget = make_overlay(&mut ui, &slot_widget, "lid", true);
+    let _ = spawn_ui_tree(&mut app.world, ui, ());
+
+    let item = build_element::<MouseLButtonMain>(&mut app.world,
+            InteractiveElementBuilder::new()
+                .press_on_click()
+                .unpress_on_unclick_home_or_away()
+                .with_drag_payload(InventoryItem(7))
+                .on_drag_start(log_drag("start"))
+                .on_drag(log_drag("drag"))
+                .on_drag_end(log_drag("end")),
+            item_widget,
+        );
+    let slot = build_element::<MouseLButtonMain>(&mut app.world,
+            InteractiveElementBuilder::new()
+                .as_drop_target(DropTarget::accepting::<InventoryItem>())
+                .on_drop(log_drag("drop")),
+            slot_widget,
+        );
+    let lid = app.world.spawn((lid_widget, DropTarget::accepting::<String>())).id();
+
+    let cursor = spawn_cursor(&mut app.world);
+    place_cursor(&mut app.world, cursor, None, Vec2::new(20., 20.));
+    app.update();
+
+    DragScene{ cursor, item, slot, lid }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn take_drag_log(world: &mut World) -> Vec<(&'static str, Option<Entity>)>
+{
+    std::mem::take(&mut world.resource_mut::<DragLog>().0)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn try_build_element(
+    In(builder)  : In<InteractiveElementBuilder>,
+    mut commands : Commands,
+    despawner    : Res<AutoDespawner>,
+) -> Result<(), InteractiveElementBuilderError>
+{
+    let mut entity_commands = commands.spawn_empty();
+    builder.build::<MouseLButtonMain>(&despawner, &mut entity_commands, Widget::new("element"))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn drag_payload_downcasts()
+{
+    let payload = DragPayload::new(InventoryItem(7));
+    assert!(payload.is::<InventoryItem>());
+    assert!(!payload.is::<u32>());
+    assert_eq!(payload.get::<InventoryItem>(), Some(&InventoryItem(7)));
+    assert_eq!(payload.get::<u32>(), None);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn drop_target_filters()
+{
+    let item = DragPayload::new(InventoryItem(7));
+    let other = DragPayload::new(String::from("other"));
+
+    let any = DropTarget::any();
+    assert!(any.accepts(None));
+    assert!(any.accepts(Some(&item)));
+
+    let items = DropTarget::accepting::<InventoryItem>();
+    assert!(!items.accepts(None));
+    assert!(items.accepts(Some(&item)));
+    assert!(!items.accepts(Some(&other)));
+
+    let small_items = DropTarget::accepting_if(|item: &InventoryItem| item.0 < 5);
+    assert!(!small_items.accepts(Some(&item)));
+    assert!(small_items.accepts(Some(&DragPayload::new(InventoryItem(3)))));
+    assert!(!small_items.accepts(Some(&other)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn widget_relative_positions()
+{
+    let mut ui = UiTree::<MainUi>::new("ui");
+    let root = relative_widget(&mut ui, "root", (0., 100.), (0., 100.));
+    let widget = relative_widget(&mut ui, root.end("widget"), (20., 60.), (50., 100.));
+    ui.compute(Vec2::ZERO, 100., 200.);
+
+    assert_eq!(widget_relative_position(&ui, &widget, Vec2::new(40., 150.)), Some(Vec2::new(0.5, 0.5)));
+    assert_eq!(widget_relative_position(&ui, &widget, Vec2::new(10., 150.)), None);
+    assert_eq!(widget_relative_position_unbounded(&ui, &widget, Vec2::new(10., 150.)), Some(Vec2::new(-0.25, 0.5)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn drag_builder_invariants()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn();
+
+    // drags require a press activator
+    let builder = InteractiveElementBuilder::new()
+        .with_drag_payload(InventoryItem(7));
+    assert_eq!(
+            syscall(&mut app.world, builder, try_build_element),
+            Err(InteractiveElementBuilderError::MissingPressActivator)
+        );
+
+    // drop callbacks require a drop target
+    let builder = InteractiveElementBuilder::new()
+        .on_drop(|In(_): In<DragInfo>| ());
+    assert_eq!(
+            syscall(&mut app.world, builder, try_build_element),
+            Err(InteractiveElementBuilderError::MissingDropTarget)
+        );
+
+    // draggable elements and drop targets
+    let builder = InteractiveElementBuilder::new()
+        .press_on_click()
+        .unpress_on_unclick_home_or_away()
+        .with_drag_payload(InventoryItem(7))
+        .on_drag(|In(_): In<DragInfo>, _world: &mut World| ());
+    assert_eq!(syscall(&mut app.world, builder, try_build_element), Ok(()));
+
+    let builder = InteractiveElementBuilder::new()
+        .as_drop_target(DropTarget::accepting::<InventoryItem>())
+        .on_drop(|In(_): In<DragInfo>| ());
+    assert_eq!(syscall(&mut app.world, builder, try_build_element), Ok(()));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn drag_and_drop_on_target()
+{
+    let mut app = ui_test_app();
+    let scene = drag_scene(&mut app);
+
+    // press the item to start dragging
+    press_mouse(&mut app.world);
+    app.update();
+    assert!(app.world.get::<Pressed>(scene.item).is_some());
+    assert!(app.world.get::<Dragging>(scene.item).is_some());
+    assert_eq!(take_drag_log(&mut app.world), vec![("start", None), ("drag", None)]);
+
+    // drag over the slot (the lid on top of the slot doesn't accept items)
+    place_cursor(&mut app.world, scene.cursor, None, Vec2::new(75., 75.));
+    app.update();
+    let info = *app.world.get::<Dragging>(scene.item).unwrap().info();
+    assert_eq!(info.drop_target(), Some(scene.slot));
+    assert_eq!(info.start(), Vec2::new(20., 20.));
+    assert_eq!(info.position(), Vec2::new(75., 75.));
+    assert_eq!(info.grab(), Vec2::new(0.5, 0.5));
+    assert_eq!(take_drag_log(&mut app.world), vec![("drag", Some(scene.slot))]);
+
+    // release to drop on the slot
+    release_mouse(&mut app.world);
+    app.update();
+    assert!(app.world.get::<Pressed>(scene.item).is_none());
+    assert!(app.world.get::<Dragging>(scene.item).is_none());
+    assert_eq!(take_drag_log(&mut app.world), vec![("drop", Some(scene.slot)), ("end", Some(scene.slot))]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn drag_aborted_without_drop()
+{
+    let mut app = ui_test_app();
+    let scene = drag_scene(&mut app);
+
+    // drag over the slot
+    press_mouse(&mut app.world);
+    app.update();
+    place_cursor(&mut app.world, scene.cursor, None, Vec2::new(75., 75.));
+    app.update();
+    assert_eq!(app.world.get::<Dragging>(scene.item).unwrap().info().drop_target(), Some(scene.slot));
+    let _ = take_drag_log(&mut app.world);
+
+    // aborting the press ends the drag without dropping
+    assert!(try_callback::<AbortPress>(&mut app.world, scene.item));
+    assert!(app.world.get::<Pressed>(scene.item).is_none());
+    assert!(app.world.get::<Dragging>(scene.item).is_none());
+    assert_eq!(take_drag_log(&mut app.world), vec![("end", None)]);
+
+    // releasing afterward does nothing
+    release_mouse(&mut app.world);
+    app.update();
+    assert_eq!(take_drag_log(&mut app.world), vec![]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn barriers_block_drops()
+{
+    let mut app = ui_test_app();
+    let scene = drag_scene(&mut app);
+
+    // an upper tree with a barrier over the right half of the window
+    let (mut ui, root) = new_ui_tree();
+    let barrier_widget = relative_widget(&mut ui, root.end("barrier"), (50., 100.), (0., 100.));
+    let upper = spawn_ui_tree(&mut app.world, ui, UiTreeLayer(1));
+    let barrier = app.world.spawn((barrier_widget, UiInteractionBarrier::<MainUi>::default(), InUiTree(upper))).id();
+
+    // the barrier's tree occludes the slot
+    press_mouse(&mut app.world);
+    app.update();
+    place_cursor(&mut app.world, scene.cursor, None, Vec2::new(75., 75.));
+    app.update();
+    assert_eq!(app.world.get::<Dragging>(scene.item).unwrap().info().drop_target(), None);
+
+    // a barrier in the slot's tree that is above the slot blocks it
+    app.world.despawn(barrier);
+    app.world.entity_mut(scene.lid).insert(UiInteractionBarrier::<MainUi>::default());
+    app.update();
+    assert_eq!(app.world.get::<Dragging>(scene.item).unwrap().info().drop_target(), None);
+
+    // removing the barriers exposes the slot
+    app.world.entity_mut(scene.lid).remove::<UiInteractionBarrier<MainUi>>();
+    app.update();
+    assert_eq!(app.world.get::<Dragging>(scene.item).unwrap().info().drop_target(), Some(scene.slot));
+    let _ = take_drag_log(&mut app.world);
+
+    // releasing over a barrier doesn't drop
+    app.world.entity_mut(scene.lid).insert(UiInteractionBarrier::<MainUi>::default());
+    app.update();
+    let _ = take_drag_log(&mut app.world);
+    release_mouse(&mut app.world);
+    app.update();
+    assert_eq!(take_drag_log(&mut app.world), vec![("end", None)]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
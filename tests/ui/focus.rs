@@ -0,0 +1,317 @@
+//local shortcuts
+use super::utils::*;
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::Command;
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Buttons in a 2x2 grid.
+struct FocusGrid
+{
+    cursor       : Entity,
+    top_left     : Entity,
+    top_right    : Entity,
+    bottom_left  : Entity,
+    bottom_right : Entity,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Make an app with focus navigation and a 2x2 grid of focusable, hoverable buttons.
+/// - Buttons are centered at `(25, 25)`, `(75, 25)`, `(25, 75)`, and `(75, 75)`.
+fn focus_grid() -> (App, FocusGrid)
+{
+    let mut app = ui_test_app();
+    app.add_plugins(FocusNavigationPlugin);
+
+    let (mut ui, root) = new_ui_tree();
+    let mut buttons = Vec::new();
+    for (name, x, y) in [("tl", 10., 10.), ("tr", 60., 10.), ("bl", 10., 60.), ("br", 60., 60.)]
+    {
+        let widget = relative_widget(&mut ui, root.end(name), (x, x + 30.), (y, y + 30.));
+        buttons.push((name, widget));
+    }
+    let _ = spawn_ui_tree(&mut app.world, ui, ());
+
+    let buttons: Vec<Entity> = buttons
+        .into_iter()
+        .map(|(name, widget)|
+            build_element::<KeyboardConfirmMain>(&mut app.world,
+                InteractiveElementBuilder::new()
+                    .on_hover_start(|| ())
+                    .on_click(log_call(name)),
+                widget,
+            )
+        )
+        .collect();
+
+    let cursor = app.world.spawn(MainFocusCursor).id();
+    app.update();
+
+    let grid = FocusGrid{
+            cursor,
+            top_left     : buttons[0],
+            top_right    : buttons[1],
+            bottom_left  : buttons[2],
+            bottom_right : buttons[3],
+        };
+    (app, grid)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn focused(world: &mut World) -> Vec<Entity>
+{
+    world.query_filtered::<Entity, With<Focused>>().iter(world).collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn move_focus(world: &mut World, direction: FocusDirection) -> Vec<Entity>
+{
+    MoveFocus::<MainUi, MainFocusCursor>::new(direction).apply(world);
+    focused(world)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn cursor_location(world: &World, cursor: Entity) -> CursorLocation
+{
+    *world.get::<CursorLocation>(cursor).unwrap()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn tab_order_wraps()
+{
+    let (mut app, grid) = focus_grid();
+    let world = &mut app.world;
+
+    // next: top-to-bottom then left-to-right, wrapping around
+    assert_eq!(move_focus(world, FocusDirection::Next), vec![grid.top_left]);
+    assert_eq!(move_focus(world, FocusDirection::Next), vec![grid.top_right]);
+    assert_eq!(move_focus(world, FocusDirection::Next), vec![grid.bottom_left]);
+    assert_eq!(move_focus(world, FocusDirection::Next), vec![grid.bottom_right]);
+    assert_eq!(move_focus(world, FocusDirection::Next), vec![grid.top_left]);
+
+    // previous: wraps around backward
+    assert_eq!(move_focus(world, FocusDirection::Previous), vec![grid.bottom_right]);
+    assert_eq!(move_focus(world, FocusDirection::Previous), vec![grid.bottom_left]);
+
+    // previous with nothing focused starts at the end
+    SetFocus(None).apply(world);
+    assert_eq!(move_focus(world, FocusDirection::Previous), vec![grid.bottom_right]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn focus_order_overrides_position()
+{
+    let (mut app, grid) = focus_grid();
+    let world = &mut app.world;
+    world.entity_mut(grid.bottom_right).insert(FocusOrder(-1));
+    world.entity_mut(grid.top_left).insert(FocusOrder(1));
+
+    assert_eq!(move_focus(world, FocusDirection::Next), vec![grid.bottom_right]);
+    assert_eq!(move_focus(world, FocusDirection::Next), vec![grid.top_right]);
+    assert_eq!(move_focus(world, FocusDirection::Next), vec![grid.bottom_left]);
+    assert_eq!(move_focus(world, FocusDirection::Next), vec![grid.top_left]);
+    assert_eq!(move_focus(world, FocusDirection::Next), vec![grid.bottom_right]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn directional_focus()
+{
+    let (mut app, grid) = focus_grid();
+    let world = &mut app.world;
+    SetFocus(Some(grid.top_left)).apply(world);
+
+    assert_eq!(move_focus(world, FocusDirection::Right), vec![grid.top_right]);
+    assert_eq!(move_focus(world, FocusDirection::Down), vec![grid.bottom_right]);
+    assert_eq!(move_focus(world, FocusDirection::Left), vec![grid.bottom_left]);
+    assert_eq!(move_focus(world, FocusDirection::Up), vec![grid.top_left]);
+
+    // no candidates in the direction: focus doesn't move
+    assert_eq!(move_focus(world, FocusDirection::Up), vec![grid.top_left]);
+    assert_eq!(move_focus(world, FocusDirection::Left), vec![grid.top_left]);
+
+    // disabled elements are skipped
+    world.entity_mut(grid.top_right).insert(Disabled);
+    assert_eq!(move_focus(world, FocusDirection::Right), vec![grid.bottom_right]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn set_focus()
+{
+    let (mut app, grid) = focus_grid();
+    let world = &mut app.world;
+
+    SetFocus(Some(grid.top_left)).apply(world);
+    assert_eq!(focused(world), vec![grid.top_left]);
+    SetFocus(Some(grid.bottom_right)).apply(world);
+    assert_eq!(focused(world), vec![grid.bottom_right]);
+
+    // focusing a despawned entity clears the focus
+    let despawned = world.spawn_empty().id();
+    world.despawn(despawned);
+    SetFocus(Some(despawned)).apply(world);
+    assert_eq!(focused(world), vec![]);
+
+    SetFocus(Some(grid.top_left)).apply(world);
+    SetFocus(None).apply(world);
+    assert_eq!(focused(world), vec![]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn focus_cursor_follows_focus()
+{
+    let (mut app, grid) = focus_grid();
+    assert!(cursor_location(&app.world, grid.cursor).is_outside());
+
+    // the cursor is placed at the center of the focused element, which is hovered
+    SetFocus(Some(grid.bottom_right)).apply(&mut app.world);
+    app.update();
+    let location = cursor_location(&app.world, grid.cursor);
+    assert!(!location.is_outside());
+    assert_eq!(location.window(), None);
+    assert_eq!(location.screen(), Vec2::new(75., 75.));
+    assert_eq!(location.world(), Vec2::new(75., -75.));
+    assert!(app.world.get::<Hovered>(grid.bottom_right).is_some());
+
+    // clearing the focus moves the cursor outside all windows
+    SetFocus(None).apply(&mut app.world);
+    app.update();
+    assert!(cursor_location(&app.world, grid.cursor).is_outside());
+    assert!(app.world.get::<Hovered>(grid.bottom_right).is_none());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn keyboard_confirm_clicks_focused()
+{
+    let (mut app, grid) = focus_grid();
+    SetFocus(Some(grid.top_right)).apply(&mut app.world);
+    app.update();
+
+    // confirm keys click the focused element
+    for key in [KeyCode::Return, KeyCode::Space]
+    {
+        app.world.resource_mut::<Input<KeyCode>>().press(key);
+        app.update();
+        assert_eq!(take_calls(&mut app.world), vec!["tr"]);
+
+        // holding the key doesn't click again
+        app.update();
+        assert_eq!(take_calls(&mut app.world), Vec::<&'static str>::new());
+
+        app.world.resource_mut::<Input<KeyCode>>().release(key);
+        app.update();
+    }
+
+    // other keys don't click
+    app.world.resource_mut::<Input<KeyCode>>().press(KeyCode::A);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), Vec::<&'static str>::new());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn keyboard_focus_navigation_then_confirm()
+{
+    let (mut app, grid) = focus_grid();
+
+    // tab focuses the first element, and the arrow keys move the focus
+    for key in [KeyCode::Tab, KeyCode::Down]
+    {
+        app.world.resource_mut::<Input<KeyCode>>().press(key);
+        app.update();
+        app.world.resource_mut::<Input<KeyCode>>().release(key);
+    }
+    assert_eq!(focused(&mut app.world), vec![grid.bottom_left]);
+
+    // the newly focused element can be clicked in the same frame
+    app.world.resource_mut::<Input<KeyCode>>().press(KeyCode::Return);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), vec!["bl"]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn confirm_sources_target_their_own_elements()
+{
+    let mut app = ui_test_app();
+    app.add_plugins(FocusNavigationPlugin);
+
+    let (mut ui, root) = new_ui_tree();
+    let key_widget = relative_widget(&mut ui, root.end("key"), (10., 40.), (10., 40.));
+    let pad_widget = relative_widget(&mut ui, root.end("pad"), (60., 90.), (10., 40.));
+    let _ = spawn_ui_tree(&mut app.world, ui, ());
+
+    // each element is built for one confirm source
+    let key = build_element::<KeyboardConfirmMain>(&mut app.world,
+            InteractiveElementBuilder::new()
+                .on_hover(log_call("hover key"))
+                .on_click(log_call("key")),
+            key_widget,
+        );
+    let pad = build_element::<GamepadConfirmMain>(&mut app.world,
+            InteractiveElementBuilder::new()
+                .on_hover(log_call("hover pad"))
+                .on_click(log_call("pad")),
+            pad_widget,
+        );
+    app.world.spawn(MainFocusCursor);
+    app.update();
+    let south = GamepadButton::new(Gamepad::new(0), GamepadButtonType::South);
+
+    // the focused element is hovered once per tick
+    SetFocus(Some(key)).apply(&mut app.world);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), vec!["hover key"]);
+
+    // only the element's own source clicks it
+    app.world.resource_mut::<Input<GamepadButton>>().press(south);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), vec!["hover key"]);
+    app.world.resource_mut::<Input<GamepadButton>>().release(south);
+    app.update();
+    let _ = take_calls(&mut app.world);
+
+    app.world.resource_mut::<Input<KeyCode>>().press(KeyCode::Return);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), vec!["key", "hover key"]);
+    app.world.resource_mut::<Input<KeyCode>>().release(KeyCode::Return);
+    app.update();
+    let _ = take_calls(&mut app.world);
+
+    // same for the gamepad element
+    SetFocus(Some(pad)).apply(&mut app.world);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), vec!["hover pad"]);
+
+    app.world.resource_mut::<Input<GamepadButton>>().press(south);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), vec!["pad", "hover pad"]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
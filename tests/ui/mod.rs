@@ -0,0 +1,7 @@
+//test modules
+mod cleanup;
+mod drag_drop;
+mod focus;
+mod touch;
+mod ui_trees;
+mod utils;
@@ -0,0 +1,200 @@
+//local shortcuts
+use super::utils::*;
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::input::InputPlugin;
+use bevy::input::touch::TouchPhase;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_lunex::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn touch_app() -> App
+{
+    let mut app = App::new();
+    app.add_plugins(InputPlugin)
+        .register_touch_source::<MainUi, 0>()
+        .register_touch_source::<MainUi, 1>();
+    app.world.spawn((Window::default(), PrimaryWindow));
+    app
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawn a button for touch slot `0` that covers screen positions `(128, 72)..(384, 216)` in the primary window.
+fn touch_button(app: &mut App) -> Entity
+{
+    app.init_resource::<CallLog>();
+
+    // the tree covers the window (tree positions are world positions with y pointing down)
+    let (mut ui, root) = new_ui_tree();
+    let button_widget = relative_widget(&mut ui, root.end("button"), (10., 30.), (10., 30.));
+    ui.compute(Vec2::new(-640.0, -360.0), 1280.0, 720.0);
+    app.world.spawn(ui);
+
+    build_element::<TouchMain<0>>(&mut app.world,
+            InteractiveElementBuilder::new()
+                .press_on_click()
+                .unpress_on_unclick_home_or_away()
+                .on_startpress(log_call("press"))
+                .on_unpress(log_call("unpress"))
+                .on_abortpress(log_call("abort")),
+            button_widget,
+        )
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn send_touch(app: &mut App, id: u64, phase: TouchPhase, position: Vec2)
+{
+    app.world.send_event(TouchInput{ phase, position, force: None, id });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn cursor_location<const SLOT: usize>(world: &mut World) -> Option<CursorLocation>
+{
+    world.query_filtered::<&CursorLocation, With<TouchCursor<SLOT>>>().get_single(world).ok().copied()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn touch_slot_is_clicked<const SLOT: usize>(
+    source  : Res<TouchMain<SLOT>>,
+    touches : Res<Touches>,
+    slots   : Res<TouchSlots>,
+) -> bool
+{
+    source.is_clicked(&(touches, slots))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn touch_slots_follow_touches()
+{
+    let mut app = touch_app();
+    assert_eq!(app.world.resource::<TouchSlots>().len(), 2);
+    assert!(cursor_location::<0>(&mut app.world).is_none());
+
+    // touches are assigned to slots in the frame after they are received
+    send_touch(&mut app, 7, TouchPhase::Started, Vec2::new(100.0, 100.0));
+    send_touch(&mut app, 8, TouchPhase::Started, Vec2::new(200.0, 300.0));
+    app.update();
+    app.update();
+    assert_eq!(app.world.resource::<TouchSlots>().touch_id(0), Some(7));
+    assert_eq!(app.world.resource::<TouchSlots>().slot(8), Some(1));
+    assert!(syscall(&mut app.world, (), touch_slot_is_clicked::<0>));
+    assert!(syscall(&mut app.world, (), touch_slot_is_clicked::<1>));
+
+    // touch cursors are positioned at the touch points (the default window is 1280x720)
+    let location = cursor_location::<0>(&mut app.world).unwrap();
+    assert_eq!(location.screen(), Vec2::new(100.0, 100.0));
+    assert_eq!(location.world(), Vec2::new(-540.0, 260.0));
+    assert_eq!(cursor_location::<1>(&mut app.world).unwrap().screen(), Vec2::new(200.0, 300.0));
+
+    // moving a touch moves its cursor
+    send_touch(&mut app, 7, TouchPhase::Moved, Vec2::new(150.0, 100.0));
+    app.update();
+    app.update();
+    assert_eq!(cursor_location::<0>(&mut app.world).unwrap().screen(), Vec2::new(150.0, 100.0));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn touch_slots_released_and_canceled()
+{
+    let mut app = touch_app();
+    send_touch(&mut app, 7, TouchPhase::Started, Vec2::new(100.0, 100.0));
+    send_touch(&mut app, 8, TouchPhase::Started, Vec2::new(200.0, 300.0));
+    app.update();
+    app.update();
+
+    // released touches keep their cursor for one frame so the release can be handled at the last touch point
+    send_touch(&mut app, 7, TouchPhase::Moved, Vec2::new(120.0, 100.0));
+    send_touch(&mut app, 7, TouchPhase::Ended, Vec2::new(120.0, 100.0));
+    app.update();
+    app.update();
+    assert_eq!(app.world.resource::<TouchSlots>().touch_id(0), Some(7));
+    assert!(!syscall(&mut app.world, (), touch_slot_is_clicked::<0>));
+    assert_eq!(cursor_location::<0>(&mut app.world).unwrap().screen(), Vec2::new(120.0, 100.0));
+
+    app.update();
+    assert_eq!(app.world.resource::<TouchSlots>().touch_id(0), None);
+    assert!(cursor_location::<0>(&mut app.world).is_none());
+
+    // canceled touches lose their cursor immediately
+    send_touch(&mut app, 8, TouchPhase::Canceled, Vec2::new(200.0, 300.0));
+    app.update();
+    app.update();
+    assert_eq!(app.world.resource::<TouchSlots>().touch_id(1), None);
+    assert!(cursor_location::<1>(&mut app.world).is_none());
+
+    // new touches take the lowest free slot
+    send_touch(&mut app, 9, TouchPhase::Started, Vec2::new(10.0, 10.0));
+    app.update();
+    app.update();
+    assert_eq!(app.world.resource::<TouchSlots>().slot(9), Some(0));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn touch_presses_element()
+{
+    let mut app = touch_app();
+    let button = touch_button(&mut app);
+
+    // a touch over the button presses it once the touch is assigned to a slot
+    send_touch(&mut app, 7, TouchPhase::Started, Vec2::new(200.0, 150.0));
+    app.update();
+    app.update();
+    assert!(app.world.get::<Pressed>(button).is_some());
+    assert_eq!(take_calls(&mut app.world), vec!["press"]);
+
+    // ending the touch unpresses the button
+    send_touch(&mut app, 7, TouchPhase::Ended, Vec2::new(200.0, 150.0));
+    app.update();
+    app.update();
+    assert!(app.world.get::<Pressed>(button).is_none());
+    assert_eq!(take_calls(&mut app.world), vec!["unpress"]);
+
+    // touches outside the button are ignored
+    send_touch(&mut app, 8, TouchPhase::Started, Vec2::new(600.0, 150.0));
+    app.update();
+    app.update();
+    assert!(app.world.get::<Pressed>(button).is_none());
+    assert_eq!(take_calls(&mut app.world), Vec::<&'static str>::new());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn touch_cancel_aborts_press()
+{
+    let mut app = touch_app();
+    let button = touch_button(&mut app);
+
+    send_touch(&mut app, 7, TouchPhase::Started, Vec2::new(200.0, 150.0));
+    app.update();
+    app.update();
+    assert!(app.world.get::<Pressed>(button).is_some());
+    assert_eq!(take_calls(&mut app.world), vec!["press"]);
+
+    // canceling the touch loses the touch cursor, which aborts the press
+    send_touch(&mut app, 7, TouchPhase::Canceled, Vec2::new(200.0, 150.0));
+    app.update();
+    app.update();
+    assert!(app.world.get::<Pressed>(button).is_none());
+    assert_eq!(take_calls(&mut app.world), vec!["abort"]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
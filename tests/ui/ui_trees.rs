@@ -0,0 +1,213 @@
+//local shortcuts
+use super::utils::*;
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy_lunex::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn tree_windows(In(trees): In<Vec<Entity>>, uis: UiTrees<MainUi>) -> Vec<Option<Entity>>
+{
+    trees.iter().map(|tree| uis.window(*tree)).collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn trees_in_window(In(window): In<Option<Entity>>, uis: UiTrees<MainUi>) -> Vec<Entity>
+{
+    uis.in_window(window).into_iter().map(|(tree, _)| tree).collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn tree_occludes(In((upper, lower)): In<(Entity, Entity)>, uis: UiTrees<MainUi>) -> bool
+{
+    uis.occludes(upper, lower)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn widget_is_visible(world: &World, tree: Entity, widget: &Widget) -> bool
+{
+    widget.fetch(world.get::<UiTree<MainUi>>(tree).unwrap()).unwrap().is_visible()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn same_tag_trees_in_two_windows()
+{
+    let mut app = ui_test_app();
+    let window_a = app.world.spawn(Window::default()).id();
+    let window_b = app.world.spawn(Window::default()).id();
+
+    // trees with the same widget paths in each window
+    let mut trees = Vec::new();
+    for window in [window_a, window_b]
+    {
+        let (mut ui, root) = new_ui_tree();
+        let _ = relative_widget(&mut ui, root.end("button"), (0., 50.), (0., 50.));
+        trees.push(spawn_ui_tree(&mut app.world, ui, UiTreeWindow(window)));
+    }
+    let (tree_a, tree_b) = (trees[0], trees[1]);
+
+    // a tree inserted on a window entity is associated with that window
+    let (mut ui, _) = new_ui_tree();
+    ui.compute(Vec2::ZERO, 100., 100.);
+    app.world.entity_mut(window_b).insert(ui);
+
+    assert_eq!(
+            syscall(&mut app.world, vec![tree_a, tree_b, window_b], tree_windows),
+            vec![Some(window_a), Some(window_b), Some(window_b)]
+        );
+    assert_eq!(syscall(&mut app.world, Some(window_a), trees_in_window), vec![tree_a]);
+    assert_eq!(syscall(&mut app.world, None, trees_in_window), Vec::<Entity>::new());
+
+    // buttons in each tree
+    let _ = build_element::<MouseLButtonMain>(&mut app.world,
+            InteractiveElementBuilder::new()
+                .in_ui_tree(tree_a)
+                .on_click(log_call("a")),
+            Widget::new("root/button"),
+        );
+    let _ = build_element::<MouseLButtonMain>(&mut app.world,
+            InteractiveElementBuilder::new()
+                .in_ui_tree(tree_b)
+                .on_click(log_call("b")),
+            Widget::new("root/button"),
+        );
+
+    // click in window a
+    let cursor = spawn_cursor(&mut app.world);
+    place_cursor(&mut app.world, cursor, Some(window_a), Vec2::new(25., 25.));
+    press_mouse(&mut app.world);
+    app.update();
+    release_mouse(&mut app.world);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), vec!["a"]);
+
+    // click in window b
+    place_cursor(&mut app.world, cursor, Some(window_b), Vec2::new(25., 25.));
+    press_mouse(&mut app.world);
+    app.update();
+    release_mouse(&mut app.world);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), vec!["b"]);
+
+    // a cursor outside all windows can't reach window trees
+    app.world.entity_mut(cursor).insert(CursorLocation::outside());
+    press_mouse(&mut app.world);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), Vec::<&'static str>::new());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn layered_trees_occlude()
+{
+    let mut app = ui_test_app();
+
+    // lower tree: a button covering the whole tree
+    let (mut ui, root) = new_ui_tree();
+    let button_widget = relative_widget(&mut ui, root.end("button"), (0., 100.), (0., 100.));
+    let lower = spawn_ui_tree(&mut app.world, ui, ());
+
+    // upper tree: a barrier covering the left half of the tree
+    let (mut ui, root) = new_ui_tree();
+    let barrier_widget = relative_widget(&mut ui, root.end("barrier"), (0., 50.), (0., 100.));
+    let upper = spawn_ui_tree(&mut app.world, ui, UiTreeLayer(1));
+    app.world.spawn((barrier_widget, UiInteractionBarrier::<MainUi>::default(), InUiTree(upper)));
+
+    let _ = build_element::<MouseLButtonMain>(&mut app.world,
+            InteractiveElementBuilder::new()
+                .in_ui_tree(lower)
+                .on_click(log_call("click")),
+            button_widget,
+        );
+
+    assert!(syscall(&mut app.world, (upper, lower), tree_occludes));
+    assert!(!syscall(&mut app.world, (lower, upper), tree_occludes));
+    assert_eq!(syscall(&mut app.world, None, trees_in_window), vec![upper, lower]);
+
+    // the barrier in the upper tree occludes the button
+    let cursor = spawn_cursor(&mut app.world);
+    place_cursor(&mut app.world, cursor, None, Vec2::new(25., 50.));
+    press_mouse(&mut app.world);
+    app.update();
+    release_mouse(&mut app.world);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), Vec::<&'static str>::new());
+
+    // the button can be clicked outside the barrier
+    place_cursor(&mut app.world, cursor, None, Vec2::new(75., 50.));
+    press_mouse(&mut app.world);
+    app.update();
+    release_mouse(&mut app.world);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), vec!["click"]);
+
+    // moving the upper tree below the lower tree exposes the button
+    app.world.entity_mut(upper).insert(UiTreeLayer(-1));
+    assert!(syscall(&mut app.world, (lower, upper), tree_occludes));
+
+    place_cursor(&mut app.world, cursor, None, Vec2::new(25., 50.));
+    press_mouse(&mut app.world);
+    app.update();
+    release_mouse(&mut app.world);
+    app.update();
+    assert_eq!(take_calls(&mut app.world), vec!["click"]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn toggle_visibility_in_one_tree()
+{
+    let mut app = App::new();
+
+    // trees with the same widget paths
+    let mut trees = Vec::new();
+    let mut widgets = (Widget::new("root/on"), Widget::new("root/off"));
+    for _ in 0..2
+    {
+        let (mut ui, root) = new_ui_tree();
+        let on = make_overlay(&mut ui, &root, "on", false);
+        let off = make_overlay(&mut ui, &root, "off", true);
+        widgets = (on, off);
+        trees.push(spawn_ui_tree(&mut app.world, ui, ()));
+    }
+    let (tree_a, tree_b) = (trees[0], trees[1]);
+    let (on, off) = widgets;
+
+    // toggle in one tree
+    syscall(
+            &mut app.world,
+            (tree_a, [on.clone()], [off.clone()]),
+            toggle_ui_tree_visibility::<MainUi, 1, 1>
+        );
+    assert!(widget_is_visible(&app.world, tree_a, &on));
+    assert!(!widget_is_visible(&app.world, tree_a, &off));
+    assert!(!widget_is_visible(&app.world, tree_b, &on));
+    assert!(widget_is_visible(&app.world, tree_b, &off));
+
+    // toggle in all trees
+    syscall(
+            &mut app.world,
+            (MainUi, [off.clone()], [on.clone()]),
+            toggle_ui_visibility::<MainUi, 1, 1>
+        );
+    assert!(!widget_is_visible(&app.world, tree_a, &on));
+    assert!(widget_is_visible(&app.world, tree_a, &off));
+    assert!(!widget_is_visible(&app.world, tree_b, &on));
+    assert!(widget_is_visible(&app.world, tree_b, &off));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,124 @@
+//local shortcuts
+use bevy_kot::prelude::*;
+
+//third-party shortcuts
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy_lunex::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn build_element_system<S: InteractionSource>(
+    In((builder, widget)) : In<(InteractiveElementBuilder, Widget)>,
+    mut commands          : Commands,
+    despawner             : Res<AutoDespawner>,
+) -> Entity
+{
+    let mut entity_commands = commands.spawn_empty();
+    builder.build::<S>(&despawner, &mut entity_commands, widget).unwrap();
+    entity_commands.id()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records the order that test callbacks are invoked in.
+#[derive(Resource, Default, Debug)]
+pub(super) struct CallLog(pub(super) Vec<&'static str>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Make a callback system that records `name` in the [`CallLog`].
+pub(super) fn log_call(name: &'static str) -> impl FnMut(ResMut<CallLog>) + Send + Sync + 'static
+{
+    move |mut log: ResMut<CallLog>| log.0.push(name)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Make an app with input handling and a [`MouseLButtonMain`] interaction source.
+pub(super) fn ui_test_app() -> App
+{
+    let mut app = App::new();
+    app.add_plugins(InputPlugin)
+        .init_resource::<CallLog>()
+        .register_interaction_source(MouseLButtonMain::default());
+    app
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Make a UI tree with a root widget that covers the tree.
+pub(super) fn new_ui_tree() -> (UiTree<MainUi>, Widget)
+{
+    let mut ui = UiTree::<MainUi>::new("ui");
+    let root = relative_widget(&mut ui, "root", (0., 100.), (0., 100.));
+    (ui, root)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Compute a UI tree's layout in the area `(0, 0)` to `(100, 100)` and spawn it.
+pub(super) fn spawn_ui_tree(world: &mut World, mut ui: UiTree<MainUi>, bundle: impl Bundle) -> Entity
+{
+    ui.compute(Vec2::ZERO, 100., 100.);
+    world.spawn((ui, bundle)).id()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Build an interactive element for source `S`.
+pub(super) fn build_element<S: InteractionSource>(
+    world   : &mut World,
+    builder : InteractiveElementBuilder,
+    widget  : Widget,
+) -> Entity
+{
+    syscall(world, (builder, widget), build_element_system::<S>)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawn a main mouse cursor that is controlled by [`place_cursor()`] (it is not updated from OS windows).
+pub(super) fn spawn_cursor(world: &mut World) -> Entity
+{
+    world.spawn(MainMouseCursor).id()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Place a cursor at a position in UI tree coordinates.
+pub(super) fn place_cursor(world: &mut World, cursor: Entity, window: Option<Entity>, position: Vec2)
+{
+    world.entity_mut(cursor).insert(CursorLocation::new(window, position, position.invert_y()));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Press the left mouse button (seen by interaction sources on the next update).
+pub(super) fn press_mouse(world: &mut World)
+{
+    world.resource_mut::<Input<MouseButton>>().press(MouseButton::Left);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Release the left mouse button (seen by interaction sources on the next update).
+pub(super) fn release_mouse(world: &mut World)
+{
+    world.resource_mut::<Input<MouseButton>>().release(MouseButton::Left);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Take the calls recorded in the [`CallLog`].
+pub(super) fn take_calls(world: &mut World) -> Vec<&'static str>
+{
+    std::mem::take(&mut world.resource_mut::<CallLog>().0)
+}
+
+//-------------------------------------------------------------------------------------------------------------------